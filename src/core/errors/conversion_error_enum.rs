@@ -20,6 +20,11 @@ pub enum ConversionError {
     #[error("{0}")]
     Code(String),
 
+    /// Error when bytes converted to a string do not match any known
+    /// [`Guid`](crate::core::partition::Guid); `input` holds the converted string.
+    #[error("unsupported GUID: {input:?}")]
+    UnknownGuid { input: String },
+
     /// Error while converting a value to a [`MaxColWidth`](crate::core::partition_table::MaxColWidth).
     #[error("{0}")]
     MaxColWidth(String),
@@ -19,4 +19,14 @@ pub enum ParserError {
     /// Error while parsing a string into a [`Code`](crate::core::partition::Code).
     #[error("{0}")]
     Code(String),
+
+    /// Error when a well-formed string does not match any known
+    /// [`Guid`](crate::core::partition::Guid); `input` holds the string as given to the parser.
+    #[error("unsupported GUID: {input:?}")]
+    UnknownGuid { input: String },
+
+    /// Error while expanding a partition type shortcut into a
+    /// [`PartitionKind`](crate::core::partition::PartitionKind).
+    #[error("{0}")]
+    Shortcut(String),
 }
@@ -59,6 +59,43 @@ pub fn direction(&self) -> Direction {
         direction
     }
 
+    #[doc(hidden)]
+    /// Advances this iterator over the partitions in `table`, in its [`Direction`] of iteration,
+    /// and returns a pointer to the next partition, or `None` if there are no more entries.
+    ///
+    /// This is the one step shared by all iterators over [`PartitionList`](crate::core::partition::PartitionList)s.
+    pub(crate) fn next_partition(
+        &self,
+        table: *mut libfdisk::fdisk_table,
+    ) -> Option<*mut libfdisk::fdisk_partition> {
+        let mut partition_ptr = MaybeUninit::<*mut libfdisk::fdisk_partition>::zeroed();
+
+        let result = unsafe {
+            libfdisk::fdisk_table_next_partition(table, self.inner, partition_ptr.as_mut_ptr())
+        };
+
+        match result {
+            0 => {
+                log::debug!("GenIterator::next_partition got next partition");
+                let ptr = unsafe { partition_ptr.assume_init() };
+
+                Some(ptr)
+            }
+            // Reached end of list.
+            1 => {
+                log::debug!("GenIterator::next_partition reached end of partition list");
+
+                None
+            }
+            // Error occurred.
+            code => {
+                log::debug!("GenIterator::next_partition failed to get next partition. libfdisk::fdisk_table_next_partition returned error code: {:?}", code);
+
+                None
+            }
+        }
+    }
+
     /// Resets the position of the next element in the collection to that of the
     /// first element. This method keeps the [`Direction`] of iteration unchanged.
     pub fn reset(&self) {
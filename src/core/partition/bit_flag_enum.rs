@@ -9,6 +9,7 @@
 use crate::core::partition::DOSFlag;
 use crate::core::partition::GPTFlag;
 use crate::core::partition::SGIFlag;
+use crate::core::partition_table::PartitionTableKind;
 
 /// Partition flags by type of partition table.
 #[derive(Debug)]
@@ -28,6 +29,15 @@ pub fn to_u64(&self) -> u64 {
             Self::SGI(flag) => *flag as u64,
         }
     }
+
+    /// Returns the type of partition table this `BitFlag` applies to.
+    pub fn partition_table_kind(&self) -> PartitionTableKind {
+        match self {
+            Self::DOS(_) => PartitionTableKind::DOS,
+            Self::GPT(_) => PartitionTableKind::GPT,
+            Self::SGI(_) => PartitionTableKind::SGI,
+        }
+    }
 }
 
 impl From<DOSFlag> for BitFlag {
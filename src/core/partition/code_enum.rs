@@ -436,6 +436,36 @@ pub fn as_str(&self) -> &str {
     pub fn to_u32(&self) -> u32 {
         *self as u8 as u32
     }
+
+    /// Returns a curated list of commonly used `MBR` partition types, with their names as
+    /// displayed by `fdisk`, to build a menu of partition types for example.
+    ///
+    /// **Note:** this list is a small subset of the partition types supported by `libfdisk`; use
+    /// [`enum_iterator::all`] to get all variants of `Code`.
+    pub fn well_known() -> &'static [(Code, &'static str)] {
+        &[
+            (Self::EmptyPartition, "Empty"),
+            (Self::FAT12, "FAT12"),
+            (Self::ExtendedPartition, "Extended"),
+            (Self::FAT16B, "FAT16"),
+            (Self::HPFSNTFSExfat, "HPFS/NTFS/exFAT"),
+            (Self::W95FAT32, "W95 FAT32"),
+            (Self::W95FAT32LBA, "W95 FAT32 (LBA)"),
+            (Self::W95FAT16LBA, "W95 FAT16 (LBA)"),
+            (Self::W95ExtendedLBA, "W95 Ext'd (LBA)"),
+            (Self::LinuxSwap, "Linux swap / Solaris"),
+            (Self::Linux, "Linux"),
+            (Self::LinuxExtended, "Linux extended"),
+            (Self::LinuxLVM, "Linux LVM"),
+            (Self::FreeBSD, "FreeBSD"),
+            (Self::OpenBSD, "OpenBSD"),
+            (Self::HFSHFSPlus, "HFS / HFS+"),
+            (Self::FreedesktopBoot, "Linux extended boot"),
+            (Self::GPTProtectiveMBR, "GPT"),
+            (Self::EfiSystem, "EFI (FAT-12/16/32)"),
+            (Self::LinuxRaidAuto, "Linux raid autodetect"),
+        ]
+    }
 }
 
 impl AsRef<Code> for Code {
@@ -1096,4 +1126,22 @@ fn code_can_parse_a_valid_device_code() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn code_has_a_list_of_well_known_partition_types() {
+        let well_known = Code::well_known();
+
+        let actual = well_known
+            .iter()
+            .find(|(code, _)| *code == Code::Linux)
+            .map(|(_, name)| *name);
+        let expected = Some("Linux");
+        assert_eq!(actual, expected);
+
+        for (i, (code, _)) in well_known.iter().enumerate() {
+            let actual = well_known[i + 1..].iter().any(|(other, _)| other == code);
+            let expected = false;
+            assert_eq!(actual, expected);
+        }
+    }
 }
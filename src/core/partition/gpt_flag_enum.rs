@@ -54,3 +54,44 @@ pub enum GPTFlag {
     Bit62 = 62u64,
     Bit63 = 63u64,
 }
+
+impl GPTFlag {
+    /// Returns the position of this flag in a `GPT` Partition Entry's attribute bits, or `None`
+    /// for [`GPTFlag::GuidSpecific`] which does not refer to a specific bit.
+    ///
+    /// **Note:** the values of [`GPTFlag::RequiredPartition`], [`GPTFlag::NoIoBlockProtocol`],
+    /// and [`GPTFlag::LegacyBiosBootable`] are `libfdisk` identifiers, not bit positions.
+    pub fn attribute_bit(&self) -> Option<u32> {
+        match self {
+            Self::RequiredPartition => Some(0),
+            Self::NoIoBlockProtocol => Some(1),
+            Self::LegacyBiosBootable => Some(2),
+            Self::GuidSpecific => None,
+            bit => Some(*bit as u64 as u32),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn gpt_flag_can_give_its_attribute_bit_position() {
+        let flags = [
+            (GPTFlag::RequiredPartition, Some(0)),
+            (GPTFlag::NoIoBlockProtocol, Some(1)),
+            (GPTFlag::LegacyBiosBootable, Some(2)),
+            (GPTFlag::GuidSpecific, None),
+            (GPTFlag::Bit48, Some(48)),
+            (GPTFlag::Bit63, Some(63)),
+        ];
+
+        for (flag, expected) in flags {
+            let actual = flag.attribute_bit();
+            assert_eq!(actual, expected);
+        }
+    }
+}
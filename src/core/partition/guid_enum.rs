@@ -13,6 +13,177 @@
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
 
+/// Architecture names, as defined by the [Discoverable Partitions
+/// Specification](https://uapi-group.org/specifications/specs/discoverable_partitions_specification/),
+/// in the same order as the architecture-specific variants in each `*_VARIANTS` array below.
+const ARCHITECTURES: [&str; 21] = [
+    "alpha",
+    "arc",
+    "arm",
+    "arm64",
+    "ia64",
+    "loongarch64",
+    "mips",
+    "mips64",
+    "mips-le",
+    "mips64-le",
+    "parisc",
+    "ppc",
+    "ppc64",
+    "ppc64-le",
+    "riscv32",
+    "riscv64",
+    "s390",
+    "s390x",
+    "tilegx",
+    "x86",
+    "x86-64",
+];
+
+const ROOT_VARIANTS: [Guid; 21] = [
+    Guid::LinuxRootAlpha,
+    Guid::LinuxRootARC,
+    Guid::LinuxRootARM32,
+    Guid::LinuxRootARM64,
+    Guid::LinuxRootIA64,
+    Guid::LinuxRootLoongArch64,
+    Guid::LinuxRootMIPS,
+    Guid::LinuxRootMIPS64,
+    Guid::LinuxRootMIPSEL,
+    Guid::LinuxRootMIPS64EL,
+    Guid::LinuxRootPARISC,
+    Guid::LinuxRootPPC32,
+    Guid::LinuxRootPPC64,
+    Guid::LinuxRootPPC64LE,
+    Guid::LinuxRootRISCV32,
+    Guid::LinuxRootRISCV64,
+    Guid::LinuxRootS390,
+    Guid::LinuxRootS390x,
+    Guid::LinuxRootTILEGx,
+    Guid::LinuxRootx86,
+    Guid::LinuxRootx86_64,
+];
+
+const USR_VARIANTS: [Guid; 21] = [
+    Guid::LinuxUsrAlpha,
+    Guid::LinuxUsrARC,
+    Guid::LinuxUsrARM32,
+    Guid::LinuxUsrARM64,
+    Guid::LinuxUsrIA64,
+    Guid::LinuxUsrLoongArch64,
+    Guid::LinuxUsrMIPS,
+    Guid::LinuxUsrMIPS64,
+    Guid::LinuxUsrMIPSEL,
+    Guid::LinuxUsrMIPS64EL,
+    Guid::LinuxUsrPARISC,
+    Guid::LinuxUsrPPC32,
+    Guid::LinuxUsrPPC64,
+    Guid::LinuxUsrPPC64LE,
+    Guid::LinuxUsrRISCV32,
+    Guid::LinuxUsrRISCV64,
+    Guid::LinuxUsrS390,
+    Guid::LinuxUsrS390x,
+    Guid::LinuxUsrTILEGx,
+    Guid::LinuxUsrx86,
+    Guid::LinuxUsrx86_64,
+];
+
+const ROOT_VERITY_VARIANTS: [Guid; 21] = [
+    Guid::LinuxRootVerityAlpha,
+    Guid::LinuxRootVerityARC,
+    Guid::LinuxRootVerityARM32,
+    Guid::LinuxRootVerityARM64,
+    Guid::LinuxRootVerityIA64,
+    Guid::LinuxRootVerityLoongArch64,
+    Guid::LinuxRootVerityMIPS,
+    Guid::LinuxRootVerityMIPS64,
+    Guid::LinuxRootVerityMIPSEL,
+    Guid::LinuxRootVerityMIPS64EL,
+    Guid::LinuxRootVerityPARISC,
+    Guid::LinuxRootVerityPPC32,
+    Guid::LinuxRootVerityPPC64,
+    Guid::LinuxRootVerityPPC64LE,
+    Guid::LinuxRootVerityRISCV32,
+    Guid::LinuxRootVerityRISCV64,
+    Guid::LinuxRootVerityS390,
+    Guid::LinuxRootVerityS390x,
+    Guid::LinuxRootVerityTILEGx,
+    Guid::LinuxRootVerityx86,
+    Guid::LinuxRootVerityx86_64,
+];
+
+const USR_VERITY_VARIANTS: [Guid; 21] = [
+    Guid::LinuxUsrVerityAlpha,
+    Guid::LinuxUsrVerityARC,
+    Guid::LinuxUsrVerityARM32,
+    Guid::LinuxUsrVerityARM64,
+    Guid::LinuxUsrVerityIA64,
+    Guid::LinuxUsrVerityLoongArch64,
+    Guid::LinuxUsrVerityMIPS,
+    Guid::LinuxUsrVerityMIPS64,
+    Guid::LinuxUsrVerityMIPSEL,
+    Guid::LinuxUsrVerityMIPS64EL,
+    Guid::LinuxUsrVerityPARISC,
+    Guid::LinuxUsrVerityPPC32,
+    Guid::LinuxUsrVerityPPC64,
+    Guid::LinuxUsrVerityPPC64LE,
+    Guid::LinuxUsrVerityRISCV32,
+    Guid::LinuxUsrVerityRISCV64,
+    Guid::LinuxUsrVerityS390,
+    Guid::LinuxUsrVerityS390x,
+    Guid::LinuxUsrVerityTILEGx,
+    Guid::LinuxUsrVerityx86,
+    Guid::LinuxUsrVerityx86_64,
+];
+
+const ROOT_VERITY_SIG_VARIANTS: [Guid; 21] = [
+    Guid::LinuxRootVeritySigAlpha,
+    Guid::LinuxRootVeritySigARC,
+    Guid::LinuxRootVeritySigARM32,
+    Guid::LinuxRootVeritySigARM64,
+    Guid::LinuxRootVeritySigIA64,
+    Guid::LinuxRootVeritySigLoongArch64,
+    Guid::LinuxRootVeritySigMIPS,
+    Guid::LinuxRootVeritySigMIPS64,
+    Guid::LinuxRootVeritySigMIPSEL,
+    Guid::LinuxRootVeritySigMIPS64EL,
+    Guid::LinuxRootVeritySigPARISC,
+    Guid::LinuxRootVeritySigPPC32,
+    Guid::LinuxRootVeritySigPPC64,
+    Guid::LinuxRootVeritySigPPC64LE,
+    Guid::LinuxRootVeritySigRISCV32,
+    Guid::LinuxRootVeritySigRISCV64,
+    Guid::LinuxRootVeritySigS390,
+    Guid::LinuxRootVeritySigS390x,
+    Guid::LinuxRootVeritySigTILEGx,
+    Guid::LinuxRootVeritySigx86,
+    Guid::LinuxRootVeritySigx86_64,
+];
+
+const USR_VERITY_SIG_VARIANTS: [Guid; 21] = [
+    Guid::LinuxUsrVeritySigAlpha,
+    Guid::LinuxUsrVeritySigARC,
+    Guid::LinuxUsrVeritySigARM32,
+    Guid::LinuxUsrVeritySigARM64,
+    Guid::LinuxUsrVeritySigIA64,
+    Guid::LinuxUsrVeritySigLoongArch64,
+    Guid::LinuxUsrVeritySigMIPS,
+    Guid::LinuxUsrVeritySigMIPS64,
+    Guid::LinuxUsrVeritySigMIPSEL,
+    Guid::LinuxUsrVeritySigMIPS64EL,
+    Guid::LinuxUsrVeritySigPARISC,
+    Guid::LinuxUsrVeritySigPPC32,
+    Guid::LinuxUsrVeritySigPPC64,
+    Guid::LinuxUsrVeritySigPPC64LE,
+    Guid::LinuxUsrVeritySigRISCV32,
+    Guid::LinuxUsrVeritySigRISCV64,
+    Guid::LinuxUsrVeritySigS390,
+    Guid::LinuxUsrVeritySigS390x,
+    Guid::LinuxUsrVeritySigTILEGx,
+    Guid::LinuxUsrVeritySigx86,
+    Guid::LinuxUsrVeritySigx86_64,
+];
+
 /// Supported `GPT` partitions.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Sequence)]
 #[non_exhaustive]
@@ -335,10 +506,10 @@ pub enum Guid {
     /// Linux Reserved
     LinuxReserved,
 
-    // /// GNU-Hurd Linux filesystem data
-    // GNUHurdData,
-    // /// GNU-Hurd Linux Swap partition
-    // GNUHurdSwap,
+    /// GNU-Hurd Linux filesystem data (alias of [`Guid::LinuxData`])
+    GNUHurdData,
+    /// GNU-Hurd Linux Swap partition (alias of [`Guid::LinuxSwap`])
+    GNUHurdSwap,
     /// FreeBSD Boot partition
     FreeBSDBoot,
     /// FreeBSD BSD disklabel partition
@@ -360,8 +531,8 @@ pub enum Guid {
     MacOSAPFS,
     /// macOS Darwin Apple UFS container
     MacOSUFS,
-    // /// macOS Darwin ZFS
-    // MacOSZFS,
+    /// macOS Darwin ZFS (alias of [`Guid::SolarisUsr`])
+    MacOSZFS,
     /// macOS Darwin Apple RAID partition
     MacOSRAID,
     /// macOS Darwin Apple RAID partition, offline
@@ -616,8 +787,8 @@ pub enum Guid {
     /// Fuchsia Zircon boot image (slot A/B/R)
     FuchsiaZirconBoot,
 
-    // /// Fuchsia legacy uchsia-esp
-    // FuchsiaLegacyESP,
+    /// Fuchsia legacy fuchsia-esp (alias of [`Guid::EfiSystem`])
+    FuchsiaLegacyESP,
     /// Fuchsia legacy fuchsia-system
     FuchsiaLegacySystem,
     /// Fuchsia legacy fuchsia-data
@@ -817,8 +988,8 @@ pub fn as_str(&self) -> &str {
             Self::LinuxPlain => "7ffec5c9-2d00-49b7-8941-3ea10a5586b7",
             Self::LinuxLUKS => "ca7d7ccb-63ed-4c53-861c-1742536059cc",
             Self::LinuxReserved => "8da63339-0007-60c0-c436-083ac8230908",
-            // Self::GNUHurdData => "0fc63daf-8483-4772-8e79-3d69d8477de4",
-            // Self::GNUHurdSwap => "0657fd6d-a4ab-43c4-84e5-0933c84b4f4f",
+            Self::GNUHurdData => "0fc63daf-8483-4772-8e79-3d69d8477de4",
+            Self::GNUHurdSwap => "0657fd6d-a4ab-43c4-84e5-0933c84b4f4f",
             Self::FreeBSDBoot => "83bd6b9d-7f41-11dc-be0b-001560b84f0f",
             Self::FreeBSDDisklabel => "516e7cb4-6ecf-11d6-8ff8-00022d09712b",
             Self::FreeBSDSwap => "516e7cb5-6ecf-11d6-8ff8-00022d09712b",
@@ -829,7 +1000,7 @@ pub fn as_str(&self) -> &str {
             Self::MacOSHFSPlus => "48465300-0000-11aa-aa11-00306543ecac",
             Self::MacOSAPFS => "7c3457ef-0000-11aa-aa11-00306543ecac",
             Self::MacOSUFS => "55465300-0000-11aa-aa11-00306543ecac",
-            // Self::MacOSZFS => "6a898cc3-1dd2-11b2-99a6-080020736631",
+            Self::MacOSZFS => "6a898cc3-1dd2-11b2-99a6-080020736631",
             Self::MacOSRAID => "52414944-0000-11aa-aa11-00306543ecac",
             Self::MacOSRAIDOffline => "52414944-5f4f-11aa-aa11-00306543ecac",
             Self::MacOSBootRecovery => "426f6f74-0000-11aa-aa11-00306543ecac",
@@ -945,7 +1116,7 @@ pub fn as_str(&self) -> &str {
             Self::FuchsiaVolumeManager => "49fd7cb8-df15-4e73-b9d9-992070127f0f",
             Self::FuchsiaVerifiedBoot => "421a8bfc-85d9-4d85-acda-b64eec0133e9",
             Self::FuchsiaZirconBoot => "9b37fff6-2e58-466a-983a-f7926d0b04e0",
-            // Self::FuchsiaLegacyESP => "c12a7328-f81f-11d2-ba4b-00a0c93ec93b",
+            Self::FuchsiaLegacyESP => "c12a7328-f81f-11d2-ba4b-00a0c93ec93b",
             Self::FuchsiaLegacySystem => "606b000b-b7c7-4653-a7d5-b737332c899d",
             Self::FuchsiaLegacyData => "08185f0c-892d-428a-a789-dbeec8f55e6a",
             Self::FuchsiaLegacyInstall => "48435546-4953-2041-494e-5354414c4c52",
@@ -968,12 +1139,244 @@ pub fn as_str(&self) -> &str {
         }
     }
 
+    /// Returns this `Guid`'s string representation in uppercase (e.g.
+    /// `"0FC63DAF-8483-4772-8E79-3D69D8477DE4"`), for interoperability with tools expecting
+    /// uppercase GUIDs.
+    ///
+    /// **Note:** [`Guid::as_str`], and this `Guid`'s [`Display`](fmt::Display) implementation,
+    /// return the lowercase canonical form.
+    pub fn as_str_uppercase(&self) -> String {
+        self.as_str().to_uppercase()
+    }
+
     /// Converts this `Guid` to a [`CString`]
     pub fn to_c_string(&self) -> CString {
         // This Guid's string representation does not contain NULL characters,  we can safely
         // unwrap the new CString.
         CString::new(self.as_str()).unwrap()
     }
+
+    /// Returns `true` if this `Guid` identifies a partition expected to hold a mountable file
+    /// system with user or system data (e.g. [`Guid::LinuxData`], [`Guid::WindowsBasicData`],
+    /// Linux root and `/usr` partitions, etc.).
+    ///
+    /// Returns `false` for partitions that are:
+    /// - used by boot loaders (see [`Guid::is_bootloader`]),
+    /// - reserved, or holding metadata (e.g. [`Guid::MicrosoftReserved`], [`Guid::WindowsLDM`]),
+    /// - `dm-verity` hash or signature partitions,
+    /// - swap partitions,
+    /// - containers that must be unlocked or assembled before exposing a file system (e.g.
+    ///   [`Guid::LinuxLUKS`], [`Guid::LinuxLVM`], [`Guid::LinuxRaid`]).
+    pub fn is_data_partition(&self) -> bool {
+        matches!(
+            self,
+            Self::WindowsBasicData
+                | Self::WindowsLDMData
+                | Self::HPUXData
+                | Self::LinuxData
+                | Self::LinuxRootAlpha
+                | Self::LinuxRootARC
+                | Self::LinuxRootARM32
+                | Self::LinuxRootARM64
+                | Self::LinuxRootIA64
+                | Self::LinuxRootLoongArch64
+                | Self::LinuxRootMIPS
+                | Self::LinuxRootMIPS64
+                | Self::LinuxRootMIPSEL
+                | Self::LinuxRootMIPS64EL
+                | Self::LinuxRootPARISC
+                | Self::LinuxRootPPC32
+                | Self::LinuxRootPPC64
+                | Self::LinuxRootPPC64LE
+                | Self::LinuxRootRISCV32
+                | Self::LinuxRootRISCV64
+                | Self::LinuxRootS390
+                | Self::LinuxRootS390x
+                | Self::LinuxRootTILEGx
+                | Self::LinuxRootx86
+                | Self::LinuxRootx86_64
+                | Self::LinuxUsrAlpha
+                | Self::LinuxUsrARC
+                | Self::LinuxUsrARM32
+                | Self::LinuxUsrARM64
+                | Self::LinuxUsrIA64
+                | Self::LinuxUsrLoongArch64
+                | Self::LinuxUsrMIPS
+                | Self::LinuxUsrMIPS64
+                | Self::LinuxUsrMIPSEL
+                | Self::LinuxUsrMIPS64EL
+                | Self::LinuxUsrPARISC
+                | Self::LinuxUsrPPC32
+                | Self::LinuxUsrPPC64
+                | Self::LinuxUsrPPC64LE
+                | Self::LinuxUsrRISCV32
+                | Self::LinuxUsrRISCV64
+                | Self::LinuxUsrS390
+                | Self::LinuxUsrS390x
+                | Self::LinuxUsrTILEGx
+                | Self::LinuxUsrx86
+                | Self::LinuxUsrx86_64
+                | Self::LinuxHome
+                | Self::LinuxServerData
+                | Self::LinuxPerUserHome
+                | Self::GNUHurdData
+                | Self::FreeBSDUFS
+                | Self::FreeBSDZFS
+                | Self::FreeBSDnandfs
+                | Self::MacOSHFSPlus
+                | Self::MacOSAPFS
+                | Self::MacOSUFS
+                | Self::MacOSZFS
+                | Self::SolarisRoot
+                | Self::SolarisUsr
+                | Self::SolarisVar
+                | Self::SolarisHome
+                | Self::NetBSDFFS
+                | Self::NetBSDLFS
+                | Self::ChromeOSRootFs
+                | Self::CoreOSUsr
+                | Self::CoreOSResize
+                | Self::HaikuBFS
+                | Self::MidnightBSDData
+                | Self::MidnightBSDUFS
+                | Self::MidnightBSDZFS
+                | Self::OpenBSDData
+                | Self::QNX6Fs
+                | Self::VMWareVMFS
+                | Self::AndroidSystem
+                | Self::AndroidCache
+                | Self::AndroidData
+                | Self::AndroidVendor
+                | Self::Android6Ext
+                | Self::AtariTOSBasicData
+                | Self::FuchsiaSystemData
+                | Self::FuchsiaLegacySystem
+                | Self::FuchsiaLegacyData
+                | Self::Minix
+        )
+    }
+
+    /// Returns `true` if this `Guid` identifies a partition holding a boot loader, boot loader
+    /// configuration, or kernel images loaded at boot time (e.g. [`Guid::EfiSystem`],
+    /// [`Guid::BIOSBoot`], [`Guid::LinuxXBOOTLDR`], etc.).
+    pub fn is_bootloader(&self) -> bool {
+        matches!(
+            self,
+            Self::EfiSystem
+                | Self::FuchsiaLegacyESP
+                | Self::BIOSBoot
+                | Self::SonyBoot
+                | Self::LenovoBoot
+                | Self::LinuxXBOOTLDR
+                | Self::FreeBSDBoot
+                | Self::MacOSBootRecovery
+                | Self::MacOSAPFSPreboot
+                | Self::SolarisBoot
+                | Self::ChromeOSKernel
+                | Self::MidnightBSDBoot
+                | Self::AndroidBootloader
+                | Self::AndroidBootloader2
+                | Self::AndroidBoot
+                | Self::ONIEBoot
+                | Self::PPCPrePBoot
+                | Self::FuchsiaBoot
+                | Self::FuchsiaZirconBoot
+                | Self::FuchsiaLegacyZirconBootSlotA
+                | Self::FuchsiaLegacyZirconBootSlotB
+                | Self::FuchsiaLegacyZirconBootSlotR
+                | Self::FuchsiaLegacyBoot
+        )
+    }
+
+    /// Returns the names of the other `Guid` variants sharing the same GUID string as this one.
+    ///
+    /// A few partition types use the same GUID (e.g. GNU/Hurd data partitions, and Linux data
+    /// partitions). Each such GUID has a canonical variant, the one [`Guid::from_str`] resolves
+    /// to when parsing, and one or more semantically named aliases. Aliases compare unequal to
+    /// their canonical variant, but [`Guid::as_str`] returns the same GUID for both.
+    ///
+    /// | Canonical variant       | Aliases                   |
+    /// | ----------------------- | ------------------------- |
+    /// | [`Guid::EfiSystem`]     | [`Guid::FuchsiaLegacyESP`] |
+    /// | [`Guid::LinuxData`]     | [`Guid::GNUHurdData`]     |
+    /// | [`Guid::LinuxSwap`]     | [`Guid::GNUHurdSwap`]     |
+    /// | [`Guid::SolarisUsr`]    | [`Guid::MacOSZFS`]        |
+    pub fn aliases(&self) -> &[&str] {
+        match self {
+            Self::EfiSystem => &["FuchsiaLegacyESP"],
+            Self::FuchsiaLegacyESP => &["EfiSystem"],
+            Self::LinuxData => &["GNUHurdData"],
+            Self::GNUHurdData => &["LinuxData"],
+            Self::LinuxSwap => &["GNUHurdSwap"],
+            Self::GNUHurdSwap => &["LinuxSwap"],
+            Self::SolarisUsr => &["MacOSZFS"],
+            Self::MacOSZFS => &["SolarisUsr"],
+            _ => &[],
+        }
+    }
+
+    /// Returns an iterator over the architecture-specific variants of the Linux Root partition, as
+    /// `(architecture name, variant)` pairs.
+    ///
+    /// Architecture names are those used by the [Discoverable Partitions
+    /// Specification](https://uapi-group.org/specifications/specs/discoverable_partitions_specification/):
+    /// `alpha`, `arc`, `arm`, `arm64`, `ia64`, `loongarch64`, `mips`, `mips64`, `mips-le`,
+    /// `mips64-le`, `parisc`, `ppc`, `ppc64`, `ppc64-le`, `riscv32`, `riscv64`, `s390`, `s390x`,
+    /// `tilegx`, `x86`, and `x86-64`. For example, [`Guid::LinuxRootx86_64`] is paired with
+    /// `x86-64`, and [`Guid::LinuxRootPPC64LE`] with `ppc64-le`.
+    pub fn root_variants() -> impl Iterator<Item = (&'static str, Guid)> {
+        ARCHITECTURES.into_iter().zip(ROOT_VARIANTS)
+    }
+
+    /// Returns an iterator over the architecture-specific variants of the Linux /usr partition, as
+    /// `(architecture name, variant)` pairs (see [`Guid::root_variants`]).
+    pub fn usr_variants() -> impl Iterator<Item = (&'static str, Guid)> {
+        ARCHITECTURES.into_iter().zip(USR_VARIANTS)
+    }
+
+    /// Returns an iterator over the architecture-specific variants of the Linux Root verity partition, as
+    /// `(architecture name, variant)` pairs (see [`Guid::root_variants`]).
+    pub fn root_verity_variants() -> impl Iterator<Item = (&'static str, Guid)> {
+        ARCHITECTURES.into_iter().zip(ROOT_VERITY_VARIANTS)
+    }
+
+    /// Returns an iterator over the architecture-specific variants of the Linux /usr verity partition, as
+    /// `(architecture name, variant)` pairs (see [`Guid::root_variants`]).
+    pub fn usr_verity_variants() -> impl Iterator<Item = (&'static str, Guid)> {
+        ARCHITECTURES.into_iter().zip(USR_VERITY_VARIANTS)
+    }
+
+    /// Returns an iterator over the architecture-specific variants of the Linux Root verity signature partition, as
+    /// `(architecture name, variant)` pairs (see [`Guid::root_variants`]).
+    pub fn root_verity_sig_variants() -> impl Iterator<Item = (&'static str, Guid)> {
+        ARCHITECTURES.into_iter().zip(ROOT_VERITY_SIG_VARIANTS)
+    }
+
+    /// Returns an iterator over the architecture-specific variants of the Linux /usr verity signature partition, as
+    /// `(architecture name, variant)` pairs (see [`Guid::root_variants`]).
+    pub fn usr_verity_sig_variants() -> impl Iterator<Item = (&'static str, Guid)> {
+        ARCHITECTURES.into_iter().zip(USR_VERITY_SIG_VARIANTS)
+    }
+
+    /// Returns the GNU/Hurd data partition variant, alias of [`Guid::LinuxData`].
+    pub const fn gnu_hurd_data() -> Guid {
+        Self::GNUHurdData
+    }
+
+    /// Returns the GNU/Hurd swap partition variant, alias of [`Guid::LinuxSwap`].
+    pub const fn gnu_hurd_swap() -> Guid {
+        Self::GNUHurdSwap
+    }
+
+    /// Returns the macOS ZFS partition variant, alias of [`Guid::SolarisUsr`].
+    pub const fn macos_zfs() -> Guid {
+        Self::MacOSZFS
+    }
+
+    /// Returns the Fuchsia legacy ESP variant, alias of [`Guid::EfiSystem`].
+    pub const fn fuchsia_legacy_esp() -> Guid {
+        Self::FuchsiaLegacyESP
+    }
 }
 
 impl AsRef<Guid> for Guid {
@@ -1007,7 +1410,12 @@ fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
                     e
                 ))
             })
-            .and_then(|s| Self::from_str(s).map_err(|e| ConversionError::Guid(e.to_string())))
+            .and_then(|s| {
+                Self::from_str(s).map_err(|e| match e {
+                    ParserError::UnknownGuid { input } => ConversionError::UnknownGuid { input },
+                    e => ConversionError::Guid(e.to_string()),
+                })
+            })
     }
 }
 
@@ -1023,6 +1431,10 @@ fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
 impl FromStr for Guid {
     type Err = ParserError;
 
+    /// Parses a GUID string into a `Guid`.
+    ///
+    /// **Note:** a GUID shared by several variants is resolved to its canonical variant (see
+    /// [`Guid::aliases`]).
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // Remove opening opening/closing quotes/double-quotes if present
         let err_missing_dquote = format!("missing closing double-quote in: {}", s);
@@ -1198,6 +1610,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "7ffec5c9-2d00-49b7-8941-3ea10a5586b7" => Ok(Self::LinuxPlain),
             "ca7d7ccb-63ed-4c53-861c-1742536059cc" => Ok(Self::LinuxLUKS),
             "8da63339-0007-60c0-c436-083ac8230908" => Ok(Self::LinuxReserved),
+            // Resolved to the canonical variants `LinuxData` and `LinuxSwap`.
             // "0fc63daf-8483-4772-8e79-3d69d8477de4" => Ok(Self::GNUHurdData),
             // "0657fd6d-a4ab-43c4-84e5-0933c84b4f4f" => Ok(Self::GNUHurdSwap),
             "83bd6b9d-7f41-11dc-be0b-001560b84f0f" => Ok(Self::FreeBSDBoot),
@@ -1210,6 +1623,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "48465300-0000-11aa-aa11-00306543ecac" => Ok(Self::MacOSHFSPlus),
             "7c3457ef-0000-11aa-aa11-00306543ecac" => Ok(Self::MacOSAPFS),
             "55465300-0000-11aa-aa11-00306543ecac" => Ok(Self::MacOSUFS),
+            // Resolved to the canonical variant `SolarisUsr`.
             // "6a898cc3-1dd2-11b2-99a6-080020736631" => Ok(Self::MacOSZFS),
             "52414944-0000-11aa-aa11-00306543ecac" => Ok(Self::MacOSRAID),
             "52414944-5f4f-11aa-aa11-00306543ecac" => Ok(Self::MacOSRAIDOffline),
@@ -1326,6 +1740,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "49fd7cb8-df15-4e73-b9d9-992070127f0f" => Ok(Self::FuchsiaVolumeManager),
             "421a8bfc-85d9-4d85-acda-b64eec0133e9" => Ok(Self::FuchsiaVerifiedBoot),
             "9b37fff6-2e58-466a-983a-f7926d0b04e0" => Ok(Self::FuchsiaZirconBoot),
+            // Resolved to the canonical variant `EfiSystem`.
             // "c12a7328-f81f-11d2-ba4b-00a0c93ec93b" => Ok(Self::FuchsiaLegacyESP),
             "606b000b-b7c7-4653-a7d5-b737332c899d" => Ok(Self::FuchsiaLegacySystem),
             "08185f0c-892d-428a-a789-dbeec8f55e6a" => Ok(Self::FuchsiaLegacyData),
@@ -1346,11 +1761,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "900b0fc5-90cd-4d4f-84f9-9f8ed579db88" => Ok(Self::FuchsiaLegacyEmmcBoot1),
             "b2b2e8d1-7c10-4ebc-a2d0-4614568260ad" => Ok(Self::FuchsiaLegacyEmmcBoot2),
             "481b2a38-0561-420b-b72a-f1c4988efc16" => Ok(Self::Minix),
-            _unsupported => {
-                let err_msg = format!("unsupported GUID: {:?}", s);
-
-                Err(ParserError::Guid(err_msg))
-            }
+            _unsupported => Err(ParserError::UnknownGuid {
+                input: s.to_owned(),
+            }),
         }
     }
 }
@@ -1379,6 +1792,21 @@ fn guid_can_not_parse_an_invalid_guid_string() {
         let _: Guid = "DUMMY".parse().unwrap();
     }
 
+    #[test]
+    fn guid_parse_error_keeps_the_unsupported_input() {
+        let actual = "DUMMY".parse::<Guid>();
+        let expected = Err(ParserError::UnknownGuid {
+            input: String::from("DUMMY"),
+        });
+        assert_eq!(actual, expected);
+
+        let actual = Guid::try_from(b"DUMMY".as_slice());
+        let expected = Err(ConversionError::UnknownGuid {
+            input: String::from("DUMMY"),
+        });
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     #[should_panic(expected = "bytes to UTF-8 string slice conversion error")]
     fn guid_can_not_convert_invalid_bytes_into_a_guid() {
@@ -1397,6 +1825,71 @@ fn guid_can_convert_valid_bytes_into_a_guid() -> crate::Result<()> {
         Ok(())
     }
 
+    #[test]
+    fn guid_can_emit_lowercase_and_uppercase_strings() -> crate::Result<()> {
+        let actual = Guid::LinuxData.to_string();
+        let expected = "0fc63daf-8483-4772-8e79-3d69d8477de4";
+        assert_eq!(actual, expected);
+
+        let actual = Guid::LinuxData.as_str_uppercase();
+        let expected = "0FC63DAF-8483-4772-8E79-3D69D8477DE4";
+        assert_eq!(actual, expected);
+
+        let actual: Guid = "0FC63DAF-8483-4772-8E79-3D69D8477DE4".parse()?;
+        let expected = Guid::LinuxData;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn guid_can_classify_data_and_bootloader_partitions() -> crate::Result<()> {
+        let actual = Guid::LinuxData.is_data_partition();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = Guid::LinuxRootVerityx86_64.is_data_partition();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let actual = Guid::EfiSystem.is_data_partition();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let actual = Guid::EfiSystem.is_bootloader();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = Guid::MicrosoftReserved.is_bootloader();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn guid_can_resolve_an_alias_to_its_canonical_variant() -> crate::Result<()> {
+        let alias = Guid::gnu_hurd_data();
+
+        let actual = alias.as_str();
+        let expected = Guid::LinuxData.as_str();
+        assert_eq!(actual, expected);
+
+        let actual: Guid = alias.as_str().parse()?;
+        let expected = Guid::LinuxData;
+        assert_eq!(actual, expected);
+
+        let actual = Guid::LinuxData.aliases();
+        let expected = &["GNUHurdData"];
+        assert_eq!(actual, expected);
+
+        let actual = Guid::Minix.aliases().is_empty();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn guid_can_parse_a_valid_guid() -> crate::Result<()> {
         let guid_str = "00000000-0000-0000-0000-000000000000";
@@ -2886,4 +3379,26 @@ fn guid_can_parse_a_valid_guid() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn guid_lists_each_architecture_specific_root_variant_once() {
+        let variants: Vec<Guid> = Guid::root_variants().map(|(_, guid)| guid).collect();
+
+        for guid in enum_iterator::all::<Guid>() {
+            let name = format!("{:?}", guid);
+            if name.starts_with("LinuxRoot") && !name.starts_with("LinuxRootVerity") {
+                let actual = variants.iter().filter(|&&variant| variant == guid).count();
+                let expected = 1;
+                assert_eq!(actual, expected, "{}", name);
+            }
+        }
+
+        let actual = Guid::root_variants().count();
+        let expected = 21;
+        assert_eq!(actual, expected);
+
+        let actual = Guid::usr_verity_sig_variants().find(|&(arch, _)| arch == "x86-64");
+        let expected = Some(("x86-64", Guid::LinuxUsrVeritySigx86_64));
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,47 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Problems detected in a partition layout.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum LayoutProblem {
+    /// Two partitions share sectors.
+    Overlap {
+        /// Identification number of the first partition, if set.
+        first: Option<usize>,
+        /// Identification number of the second partition, if set.
+        second: Option<usize>,
+        /// First sector shared by both partitions.
+        start: u64,
+        /// Last sector shared by both partitions.
+        end: u64,
+    },
+
+    /// Error unrelated to the layout itself (e.g. failing to copy a partition).
+    Unexpected(String),
+}
+
+impl fmt::Display for LayoutProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overlap {
+                first,
+                second,
+                start,
+                end,
+            } => write!(
+                f,
+                "partitions {:?} and {:?} overlap from sector {} to sector {}",
+                first, second, start, end
+            ),
+            Self::Unexpected(err_msg) => write!(f, "{}", err_msg),
+        }
+    }
+}
@@ -13,6 +13,7 @@
 pub use dos_flag_enum::DOSFlag;
 pub use gpt_flag_enum::GPTFlag;
 pub use guid_enum::Guid;
+pub use layout_problem_enum::LayoutProblem;
 pub(crate) use partition_builder_struct::PartBuilder;
 pub use partition_builder_struct::PartitionBuilder;
 pub use partition_iter_mut_struct::PartitionIterMut;
@@ -21,6 +22,9 @@
 pub use partition_kind_builder_struct::PartitionKindBuilder;
 pub use partition_kind_struct::PartitionKind;
 pub use partition_list_struct::PartitionList;
+pub(crate) use partition_spec_builder_struct::PartSpecBuilder;
+pub use partition_spec_builder_struct::PartitionSpecBuilder;
+pub use partition_spec_struct::PartitionSpec;
 pub use partition_struct::Partition;
 pub use sgi_flag_enum::SGIFlag;
 
@@ -29,11 +33,14 @@
 mod dos_flag_enum;
 mod gpt_flag_enum;
 mod guid_enum;
+mod layout_problem_enum;
 mod partition_builder_struct;
 mod partition_iter_mut_struct;
 mod partition_iter_struct;
 mod partition_kind_builder_struct;
 mod partition_kind_struct;
 mod partition_list_struct;
+mod partition_spec_builder_struct;
+mod partition_spec_struct;
 mod partition_struct;
 mod sgi_flag_enum;
@@ -4,7 +4,6 @@
 // From dependency library
 
 // From standard library
-use std::mem::MaybeUninit;
 
 // From this library
 use crate::core::errors::PartitionIterError;
@@ -59,6 +58,52 @@ pub(crate) fn new(
 
         Ok(iterator)
     }
+
+    #[doc(hidden)]
+    /// Moves the forward cursor to the next item in the list, stopping when it meets the backward
+    /// cursor.
+    fn step_forward(&mut self) -> Option<*mut libfdisk::fdisk_partition> {
+        let ptr = self.fwd_iter.next_partition(self.list.inner)?;
+
+        // Per the documentation of `DoubleEndedIterator`
+        // "It is important to note that both back and forth work on the same range, and do not cross: iteration is over when they meet in the middle."
+        if self.have_iterators_met || (self.fwd_cursor != self.bwd_cursor && ptr == self.bwd_cursor)
+        {
+            log::debug!(
+                "PartitionIter::step_forward forward and backward iterators met in the middle"
+            );
+            self.have_iterators_met = true;
+
+            None
+        } else {
+            self.fwd_cursor = ptr;
+
+            Some(ptr)
+        }
+    }
+
+    #[doc(hidden)]
+    /// Moves the backward cursor to the previous item in the list, stopping when it meets the
+    /// forward cursor.
+    fn step_backward(&mut self) -> Option<*mut libfdisk::fdisk_partition> {
+        let ptr = self.bwd_iter.next_partition(self.list.inner)?;
+
+        // Per the documentation of `DoubleEndedIterator`
+        // "It is important to note that both back and forth work on the same range, and do not cross: iteration is over when they meet in the middle."
+        if self.have_iterators_met || (self.bwd_cursor != self.fwd_cursor && ptr == self.fwd_cursor)
+        {
+            log::debug!(
+                "PartitionIter::step_backward forward and backward iterators met in the middle"
+            );
+            self.have_iterators_met = true;
+
+            None
+        } else {
+            self.bwd_cursor = ptr;
+
+            Some(ptr)
+        }
+    }
 }
 
 impl<'vec> Iterator for PartitionIter<'vec> {
@@ -67,210 +112,39 @@ impl<'vec> Iterator for PartitionIter<'vec> {
     fn next(&mut self) -> Option<Self::Item> {
         log::debug!("PartitionIter::next getting next item in `PartitionList`");
 
-        let mut partition_ptr = MaybeUninit::<*mut libfdisk::fdisk_partition>::zeroed();
-
-        let result = unsafe {
-            libfdisk::fdisk_table_next_partition(
-                self.list.inner,
-                self.fwd_iter.inner,
-                partition_ptr.as_mut_ptr(),
-            )
-        };
-
-        match result {
-            0 => {
-                let ptr = unsafe { partition_ptr.assume_init() };
-
-                // Per the documentation of `DoubleEndedIterator`
-                // "It is important to note that both back and forth work on the same range, and do not cross: iteration is over when they meet in the middle."
-                if self.have_iterators_met
-                    || (self.fwd_cursor != self.bwd_cursor && ptr == self.bwd_cursor)
-                {
-                    log::debug!(
-                        "PartitionIter::next forward and backward iterators met in the middle"
-                    );
-                    self.have_iterators_met = true;
-
-                    None
-                } else {
-                    log::debug!("PartitionIter::next got next item in `PartitionList`");
-                    self.fwd_cursor = ptr;
-                    let partition = owning_ref_from_ptr!(self.list, Partition, ptr);
-
-                    Some(partition)
-                }
-            }
-            // Reached end of list.
-            1 => {
-                log::debug!("PartitionIter::next reached end of `PartitionList`");
-
-                None
-            }
-            // Error occurred.
-            code => {
-                log::debug!("PartitionIter::next failed to get next item in `PartitionList`. libfdisk::fdisk_table_next_partition returned error code: {:?}", code);
-
-                None
-            }
-        }
+        self.step_forward()
+            .map(|ptr| owning_ref_from_ptr!(self.list, Partition, ptr))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let mut result;
-
-        // Skip n-1 entries, and update the cursor along the way.
+        // Skip n entries, and update the cursor along the way.
         for i in 0..n {
-            let mut partition_ptr = MaybeUninit::<*mut libfdisk::fdisk_partition>::zeroed();
-
-            result = unsafe {
-                libfdisk::fdisk_table_next_partition(
-                    self.list.inner,
-                    self.fwd_iter.inner,
-                    partition_ptr.as_mut_ptr(),
-                )
-            };
-
-            match result {
-                0 => {
-                    let ptr = unsafe { partition_ptr.assume_init() };
-
-                    // Per the documentation of `DoubleEndedIterator`
-                    // "It is important to note that both back and forth work on the same range, and do not cross: iteration is over when they meet in the middle."
-                    if self.have_iterators_met
-                        || (self.fwd_cursor != self.bwd_cursor && ptr == self.bwd_cursor)
-                    {
-                        log::debug!(
-                            "PartitionIter::nth forward and backward iterators met in the middle"
-                        );
-                        self.have_iterators_met = true;
-
-                        return None;
-                    } else {
-                        log::debug!("PartitionIter::nth got {}th item in `PartitionList`", i);
-                        self.fwd_cursor = ptr;
-                    }
-                }
-                // Reached end of list.
-                1 => {
-                    log::debug!("PartitionIter::nth reached end of `PartitionList`");
-
-                    return None;
-                }
-                // Error occurred.
-                code => {
-                    let err_msg = format!("failed to get {}th item in `PartitionList`", i);
-                    log::debug!("PartitionIter::nth {}. libfdisk::fdisk_table_next_partition returned error code: {:?}", err_msg, code);
-
-                    return None;
-                }
-            }
+            self.step_forward()?;
+            log::debug!("PartitionIter::nth skipped {}th item in `PartitionList`", i);
         }
+
         self.next()
     }
 }
 
 impl<'vec> DoubleEndedIterator for PartitionIter<'vec> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        let mut partition_ptr = MaybeUninit::<*mut libfdisk::fdisk_partition>::zeroed();
-
-        let result = unsafe {
-            libfdisk::fdisk_table_next_partition(
-                self.list.inner,
-                self.bwd_iter.inner,
-                partition_ptr.as_mut_ptr(),
-            )
-        };
-
-        match result {
-            0 => {
-                let ptr = unsafe { partition_ptr.assume_init() };
-
-                // Per the documentation of `DoubleEndedIterator`
-                // "It is important to note that both back and forth work on the same range, and do not cross: iteration is over when they meet in the middle."
-                if self.have_iterators_met
-                    || (self.bwd_cursor != self.fwd_cursor && ptr == self.fwd_cursor)
-                {
-                    log::debug!(
-                        "PartitionIter::next forward and backward iterators met in the middle"
-                    );
-                    self.have_iterators_met = true;
-
-                    None
-                } else {
-                    log::debug!("PartitionIter::next got next item in `PartitionList`");
-                    self.bwd_cursor = ptr;
-                    let partition = owning_ref_from_ptr!(self.list, Partition, ptr);
+        log::debug!("PartitionIter::next_back getting previous item in `PartitionList`");
 
-                    Some(partition)
-                }
-            }
-            // Reached end of list.
-            1 => {
-                log::debug!("PartitionIter::next_back reached start of `PartitionList`");
-
-                None
-            }
-            // Error occurred.
-            code => {
-                log::debug!("PartitionIter::next_back failed to get next item in `PartitionList`. libfdisk::fdisk_table_next_partition returned error code: {:?}", code);
-
-                None
-            }
-        }
+        self.step_backward()
+            .map(|ptr| owning_ref_from_ptr!(self.list, Partition, ptr))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let mut result;
-
-        // Skip n-1 entries, and update the cursor along the way.
+        // Skip n entries, and update the cursor along the way.
         for i in 0..n {
-            let mut partition_ptr = MaybeUninit::<*mut libfdisk::fdisk_partition>::zeroed();
-
-            result = unsafe {
-                libfdisk::fdisk_table_next_partition(
-                    self.list.inner,
-                    self.bwd_iter.inner,
-                    partition_ptr.as_mut_ptr(),
-                )
-            };
-            match result {
-                0 => {
-                    let ptr = unsafe { partition_ptr.assume_init() };
-
-                    // Per the documentation of `DoubleEndedIterator`
-                    // "It is important to note that both back and forth work on the same range, and do not cross: iteration is over when they meet in the middle."
-                    if self.have_iterators_met
-                        || (self.bwd_cursor != self.fwd_cursor && ptr == self.fwd_cursor)
-                    {
-                        log::debug!(
-                        "PartitionIter::nth_back forward and backward iterators met in the middle"
-                    );
-                        self.have_iterators_met = true;
-
-                        return None;
-                    } else {
-                        log::debug!(
-                            "PartitionIter::nth_back got {}th item in `PartitionList`",
-                            i
-                        );
-                        self.bwd_cursor = ptr;
-                    }
-                }
-                // Reached end of list.
-                1 => {
-                    log::debug!("PartitionIter::nth_back reached end of `PartitionList`");
-
-                    return None;
-                }
-                // Error occurred.
-                code => {
-                    let err_msg = format!("failed to get {}th item in `PartitionList`", i);
-                    log::debug!("PartitionIter::nth_back {}. libfdisk::fdisk_table_next_partition returned error code: {:?}", err_msg, code);
-
-                    return None;
-                }
-            }
+            self.step_backward()?;
+            log::debug!(
+                "PartitionIter::nth_back skipped {}th item in `PartitionList`",
+                i
+            );
         }
+
         self.next_back()
     }
 }
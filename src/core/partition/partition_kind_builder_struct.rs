@@ -26,7 +26,7 @@ pub(crate) struct PartTypeBuilder {
         default,
         setter(
             strip_option,
-            doc = "Set the partition type identifier (exclusively for `MBR` partition tables)."
+            doc = "Set the partition type identifier (exclusively for `MBR` partition tables).\n\nMutually exclusive with `guid` and `unknown_kind`, [`PartitionKindBuilder::build`] returns a [`PartitionKindBuilderError::MutuallyExclusive`] error if more than one is set."
         )
     )]
     code: Option<Code>,
@@ -36,7 +36,7 @@ pub(crate) struct PartTypeBuilder {
         default,
         setter(
             strip_option,
-            doc = "Set the partition type identifier (exclusively for `GUID` or `GPT` partition tables)."
+            doc = "Set the partition type identifier (exclusively for `GUID` or `GPT` partition tables).\n\nMutually exclusive with `code` and `unknown_kind`, [`PartitionKindBuilder::build`] returns a [`PartitionKindBuilderError::MutuallyExclusive`] error if more than one is set."
         )
     )]
     guid: Option<Guid>,
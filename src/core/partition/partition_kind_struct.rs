@@ -334,6 +334,15 @@ fn copy(&self) -> Result<PartitionKind, PartitionKindError> {
         Self::copy_partition_type(self)
     }
 
+    #[doc(hidden)]
+    /// Returns this partition type's identifier used to compare partition types: its lower-case
+    /// GUID, or its code formatted as a two-digit hexadecimal number (e.g. `0x83`).
+    pub(crate) fn type_identifier(&self) -> Option<String> {
+        self.guid()
+            .map(str::to_lowercase)
+            .or_else(|| self.code().map(|code| format!("{:#04x}", code)))
+    }
+
     /// Returns the partition type's identification code.
     pub fn code(&self) -> Option<u32> {
         let code = unsafe { libfdisk::fdisk_parttype_get_code(self.inner) };
@@ -431,6 +440,7 @@ fn drop(&mut self) {
 #[allow(unused_imports)]
 mod tests {
     use super::*;
+    use crate::core::errors::PartitionKindBuilderError;
     use pretty_assertions::{assert_eq, assert_ne};
 
     #[test]
@@ -471,12 +481,24 @@ fn partition_kind_code_and_unknown_kind_are_mutually_exclusive() {
     )]
     fn partition_kind_guid_and_unknown_kind_are_mutually_exclusive() {
         let _ = PartitionKind::builder()
-            .code(Code::Linux)
             .guid(Guid::SolarisRoot)
+            .unknown_kind(0x1234, "unknown")
             .build()
             .unwrap();
     }
 
+    #[test]
+    fn partition_kind_builder_rejects_both_code_and_guid() {
+        let result = PartitionKind::builder()
+            .code(Code::Linux)
+            .guid(Guid::LinuxData)
+            .build();
+
+        let actual = matches!(result, Err(PartitionKindBuilderError::MutuallyExclusive(_)));
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn partition_kind_can_create_an_mbr_partition_kind() -> crate::Result<()> {
         let name = "Linux Root";
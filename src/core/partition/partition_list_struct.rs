@@ -4,18 +4,34 @@
 // From dependency library
 
 // From standard library
+use std::any::Any;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::mem::MaybeUninit;
 use std::ops::Index;
 use std::ops::IndexMut;
 
 // From this library
 use crate::core::errors::PartitionListError;
+use crate::core::partition::LayoutProblem;
 use crate::core::partition::Partition;
 use crate::core::partition::PartitionIter;
 use crate::core::partition::PartitionIterMut;
 use crate::owning_mut_from_ptr;
 use crate::owning_ref_from_ptr;
 
+/// Comparator function used by [`PartitionList::sort_by`].
+type Comparator<'a> = dyn FnMut(&Partition, &Partition) -> Ordering + 'a;
+
+/// Payload of a panic caught in a [`Comparator`].
+type PanicPayload = Box<dyn Any + Send + 'static>;
+
+thread_local! {
+    /// Pointers to the comparators of the sorts in progress on this thread, the most recent on
+    /// top, each next to the payload of the panic it raised (if any).
+    static SORT_COMPARATORS: RefCell<Vec<(*mut libc::c_void, Option<PanicPayload>)>> = const { RefCell::new(Vec::new()) };
+}
+
 /// Collection of [`Partition`](crate::core::partition::Partition)s.
 ///
 /// `PartitionList` is a collection of partitions. It is not connected to a partition table, as
@@ -387,70 +403,128 @@ pub fn get_by_partition_number_mut(
             .map(|ptr| owning_mut_from_ptr!(self, Partition, ptr))
     }
 
-    // FIXME need upstream modification to accept a void* user_data parameter in the callback comparison function
-    // see http://blog.sagetheprogrammer.com/neat-rust-tricks-passing-rust-closures-to-c
-    //
-    // /// Sorts the list with a comparator function.
-    // ///
-    // /// The comparator function must define a total ordering for the elements in the list. If the
-    // /// ordering is not total, the order of the elements is unspecified.
-    // ///
-    // /// An order is a total order if it is (for all `a`, `b` and `c`):
-    // /// - total and antisymmetric: exactly one of `a < b`, `a == b` or `a > b` is true, and
-    // /// - transitive, `a < b` and `b < c` implies `a < c`. The same must hold for both `==` and `>`.
-    // pub fn sort_by<F>(&mut self, mut compare: F) -> Result<(), PartitionListError>
-    // where
-    //     F: FnMut(&Partition, &Partition) -> Ordering,
-    // {
-    //     log::debug!("PartitionList::sort_by sorting `PartitionList`");
-
-    //     let cmp = |this_ptr: *mut libfdisk::fdisk_partition,
-    //                other_ptr: *mut libfdisk::fdisk_partition|
-    //      -> libc::c_int {
-    //         let this = Partition::borrow_ptr(this_ptr);
-    //         let other = Partition::borrow_ptr(other_ptr);
-
-    //         match compare(&this, &other) {
-    //             Ordering::Less => -1,
-    //             Ordering::Equal => 0,
-    //             Ordering::Greater => 1,
-    //         }
-    //     };
-
-    //     let closure = Closure2::new(&cmp);
-    //     //let function = closure.code_ptr();
-    //     let function: unsafe extern "C" fn(
-    //         *mut libfdisk::fdisk_partition,
-    //         *mut libfdisk::fdisk_partition,
-    //     ) -> libc::c_int = closure.code_ptr();
-
-    //     // let result = unsafe {
-    //     //     // FIXME explain
-    //     //     // https://stackoverflow.com/a/70843628
-    //     //     let fn_c_ptr: unsafe extern "C" fn(
-    //     //         *mut libfdisk::fdisk_partition,
-    //     //         *mut libfdisk::fdisk_partition,
-    //     //     ) -> libc::c_int = std::mem::transmute(function);
-
-    //     //     libfdisk::fdisk_table_sort_partitions(self.inner, Some(fn_c_ptr))
-    //     // };
-
-    //     let result = unsafe { libfdisk::fdisk_table_sort_partitions(self.inner, Some(function)) };
-
-    //     match result {
-    //         0 => {
-    //             log::debug!("PartitionList::sort_by `PartitionList` sorted");
-
-    //             Ok(())
-    //         }
-    //         code => {
-    //             let err_msg = "failed to sort `PartitionList`".to_owned();
-    //             log::debug!("PartitionList::sort_by {}. libfdisk::fdisk_table_sort_partitions returned error code: {:?}", err_msg, code);
-
-    //             Err(PartitionListError::Sort(err_msg))
-    //         }
-    //     }
-    // }
+    #[doc(hidden)]
+    /// Comparison function passed to `libfdisk::fdisk_table_sort_partitions`. It forwards its
+    /// arguments to the comparator on top of the [`SORT_COMPARATORS`] stack.
+    unsafe extern "C" fn compare_trampoline(
+        this_ptr: *mut libfdisk::fdisk_partition,
+        other_ptr: *mut libfdisk::fdisk_partition,
+    ) -> libc::c_int {
+        // Release the borrow on the stack before calling the comparator, which might itself sort
+        // another list. Once a comparator has panicked, it is not called again.
+        let compare_ptr = SORT_COMPARATORS.with(|stack| match stack.borrow().last() {
+            Some((compare_ptr, None)) => Some(*compare_ptr),
+            _ => None,
+        });
+
+        match compare_ptr {
+            Some(compare_ptr) => {
+                let compare = &mut *(compare_ptr as *mut &mut Comparator);
+                let this = Partition::borrow_ptr(this_ptr);
+                let other = Partition::borrow_ptr(other_ptr);
+
+                // Unwinding across an `extern "C"` boundary aborts the process, catch panics
+                // here, `PartitionList::sort_by` resumes them once `libfdisk` returns.
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    (*compare)(&this, &other)
+                }));
+
+                match result {
+                    Ok(Ordering::Less) => -1,
+                    Ok(Ordering::Equal) => 0,
+                    Ok(Ordering::Greater) => 1,
+                    Err(payload) => {
+                        log::debug!("PartitionList::compare_trampoline comparator panicked");
+                        SORT_COMPARATORS.with(|stack| {
+                            if let Some((_, panic)) = stack.borrow_mut().last_mut() {
+                                *panic = Some(payload);
+                            }
+                        });
+
+                        0
+                    }
+                }
+            }
+            None => 0,
+        }
+    }
+
+    /// Sorts the list with a comparator function.
+    ///
+    /// The comparator function must define a total ordering for the elements in the list. If the
+    /// ordering is not total, the order of the elements is unspecified.
+    ///
+    /// An order is a total order if it is (for all `a`, `b` and `c`):
+    /// - total and antisymmetric: exactly one of `a < b`, `a == b` or `a > b` is true, and
+    /// - transitive, `a < b` and `b < c` implies `a < c`. The same must hold for both `==` and `>`.
+    ///
+    /// **Note:** `libfdisk`'s comparison callback does not take a user data pointer, so the
+    /// comparator is kept in a thread-local stack for the duration of the sort (see [Passing Rust
+    /// closures to
+    /// C](http://blog.sagetheprogrammer.com/neat-rust-tricks-passing-rust-closures-to-c)). It is
+    /// thus safe to sort another `PartitionList` from within `compare`.
+    ///
+    /// # Panics
+    ///
+    /// If `compare` panics, it is not called again, and the panic resumes after `libfdisk` returns
+    /// from sorting. The order of the elements in the list is then unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsfdisk::partition::Partition;
+    /// use rsfdisk::partition::PartitionList;
+    ///
+    /// fn main() -> rsfdisk::Result<()> {
+    ///     let mut list = PartitionList::new()?;
+    ///     list.push(Partition::builder().number(2).starting_sector(4096).build()?)?;
+    ///     list.push(Partition::builder().number(1).starting_sector(64).build()?)?;
+    ///
+    ///     list.sort_by(|p1, p2| p1.compare_starting_sectors(p2))?;
+    ///
+    ///     assert_eq!(list[0].starting_sector(), Some(64));
+    ///     assert_eq!(list[1].starting_sector(), Some(4096));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F) -> Result<(), PartitionListError>
+    where
+        F: FnMut(&Partition, &Partition) -> Ordering,
+    {
+        log::debug!("PartitionList::sort_by sorting `PartitionList`");
+
+        let mut compare_ref: &mut Comparator = &mut compare;
+        let compare_ptr = &mut compare_ref as *mut &mut Comparator as *mut libc::c_void;
+
+        SORT_COMPARATORS.with(|stack| stack.borrow_mut().push((compare_ptr, None)));
+
+        let result = unsafe {
+            libfdisk::fdisk_table_sort_partitions(self.inner, Some(Self::compare_trampoline))
+        };
+
+        let panic = SORT_COMPARATORS.with(|stack| stack.borrow_mut().pop().and_then(|(_, p)| p));
+
+        if let Some(payload) = panic {
+            log::debug!("PartitionList::sort_by resuming comparator panic");
+            std::panic::resume_unwind(payload);
+        }
+
+        match result {
+            0 => {
+                log::debug!("PartitionList::sort_by `PartitionList` sorted");
+
+                Ok(())
+            }
+            code => {
+                let err_msg = "failed to sort `PartitionList`".to_owned();
+                log::debug!("PartitionList::sort_by {}. libfdisk::fdisk_table_sort_partitions returned error code: {:?}", err_msg, code);
+
+                Err(PartitionListError::Sort(err_msg))
+            }
+        }
+    }
 
     /// Returns `true` when the list contains no elements.
     pub fn is_empty(&self) -> bool {
@@ -473,6 +547,249 @@ pub fn is_not_in_increasing_order(&self) -> bool {
 
         state
     }
+
+    #[doc(hidden)]
+    /// Returns the first and last sectors of a partition pointing to a used area on disk, or
+    /// `None` if the partition is a container, free space, or lacks location data.
+    fn used_extent(partition: &Partition) -> Option<(u64, u64)> {
+        if partition.is_container() || partition.is_free_space() {
+            return None;
+        }
+
+        let start = partition.starting_sector()?;
+        let end = partition.ending_sector().or_else(|| {
+            partition
+                .size_in_sectors()
+                .filter(|&size| size > 0)
+                .map(|size| start + size - 1)
+        })?;
+
+        Some((start, end))
+    }
+
+    #[doc(hidden)]
+    /// Returns a [`LayoutProblem::Overlap`] if `first` and `second` share sectors.
+    fn overlap(first: &Partition, second: &Partition) -> Option<LayoutProblem> {
+        let (first_start, first_end) = Self::used_extent(first)?;
+        let (second_start, second_end) = Self::used_extent(second)?;
+
+        if first_start <= second_end && second_start <= first_end {
+            Some(LayoutProblem::Overlap {
+                first: first.number(),
+                second: second.number(),
+                start: first_start.max(second_start),
+                end: first_end.min(second_end),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a [`LayoutProblem::Overlap`] for each pair of partitions in this list sharing
+    /// sectors, in the order they appear in the list.
+    ///
+    /// Containers (e.g. `DOS` extended partitions) and free space entries are not checked for
+    /// overlaps.
+    pub fn overlaps(&self) -> Vec<LayoutProblem> {
+        log::debug!("PartitionList::overlaps looking for overlapping partitions");
+
+        let partitions: Vec<&Partition> = self.iter().collect();
+        let mut problems = Vec::new();
+
+        for (i, first) in partitions.iter().enumerate() {
+            for second in partitions[i + 1..].iter() {
+                if let Some(problem) = Self::overlap(first, second) {
+                    problems.push(problem);
+                }
+            }
+        }
+
+        log::debug!("PartitionList::overlaps found overlaps: {:?}", problems);
+
+        problems
+    }
+
+    #[doc(hidden)]
+    /// Serializes the characteristics of `partition` hashed by [`PartitionList::fingerprint`].
+    pub(crate) fn fingerprint_record(partition: &Partition) -> Vec<u8> {
+        fn push_u64(record: &mut Vec<u8>, value: Option<u64>) {
+            match value {
+                Some(value) => {
+                    record.push(1);
+                    record.extend_from_slice(&value.to_le_bytes());
+                }
+                None => record.push(0),
+            }
+        }
+
+        fn push_str(record: &mut Vec<u8>, value: Option<&str>) {
+            match value {
+                Some(value) => {
+                    record.push(1);
+                    record.extend_from_slice(&(value.len() as u64).to_le_bytes());
+                    record.extend_from_slice(value.as_bytes());
+                }
+                None => record.push(0),
+            }
+        }
+
+        let kind = partition
+            .partition_type()
+            .and_then(|kind| kind.type_identifier());
+
+        let mut record = Vec::new();
+        push_u64(&mut record, partition.number().map(|n| n as u64));
+        push_u64(&mut record, partition.starting_sector());
+        push_u64(&mut record, partition.size_in_sectors());
+        push_str(&mut record, kind.as_deref());
+        push_str(&mut record, partition.name());
+        push_str(
+            &mut record,
+            partition.uuid().map(str::to_lowercase).as_deref(),
+        );
+
+        record
+    }
+
+    /// Returns a 64-bit signature of the partitions in this list, to cheaply check whether two
+    /// lists describe the same layout (e.g. two runs of a disk image build).
+    ///
+    /// The signature covers each partition's number, starting sector, size, type (GUID or code),
+    /// name, and UUID. GUIDs and UUIDs are compared case-insensitively. It does **not** depend on
+    /// the order of partitions in the list.
+    ///
+    /// The hashing scheme is stable across versions and platforms: each partition's
+    /// characteristics are serialized in the order listed above (missing values as a `0` byte,
+    /// present values as a `1` byte followed by the value; integers as 8 little-endian bytes,
+    /// strings as their 8-byte little-endian length followed by their UTF-8 bytes), the records
+    /// are sorted, then hashed in order with 64-bit FNV-1a.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut records: Vec<Vec<u8>> = self.iter().map(Self::fingerprint_record).collect();
+        records.sort();
+
+        let fingerprint = records
+            .iter()
+            .flatten()
+            .fold(FNV_OFFSET_BASIS, |hash, &byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            });
+        log::debug!("PartitionList::fingerprint value: {:#018x}", fingerprint);
+
+        fingerprint
+    }
+
+    /// Appends copies of the partitions in `other` to this list.
+    ///
+    /// Partitions from `other` are renumbered to avoid collisions: a partition whose
+    /// identification number is already in use is given the smallest number still available,
+    /// other partitions keep their number (or lack thereof).
+    ///
+    /// If any partition from `other` overlaps a partition in this list, or another partition in
+    /// `other`, this method returns the list of overlaps found and leaves this list unchanged.
+    /// Containers (e.g. `DOS` extended partitions) are not checked for overlaps. On any other
+    /// failure, this list is left unchanged as well.
+    pub fn merge(&mut self, other: &PartitionList) -> Result<(), Vec<LayoutProblem>> {
+        log::debug!(
+            "PartitionList::merge merging {:?} partitions into list",
+            other.len()
+        );
+
+        let others: Vec<&Partition> = other.iter().collect();
+        let mut problems = Vec::new();
+
+        for (i, partition) in others.iter().enumerate() {
+            for existing in self.iter().chain(others[i + 1..].iter().copied()) {
+                if let Some(problem) = Self::overlap(existing, partition) {
+                    problems.push(problem);
+                }
+            }
+        }
+
+        if !problems.is_empty() {
+            log::debug!("PartitionList::merge found overlaps: {:?}", problems);
+
+            return Err(problems);
+        }
+
+        // Reserve the numbers of all partitions keeping theirs first, so that a renumbered
+        // partition never takes the number of a partition merged after it.
+        let mut used_numbers: Vec<usize> = self.iter().filter_map(Partition::number).collect();
+        let mut numbers = Vec::with_capacity(others.len());
+
+        for partition in others.iter() {
+            match partition.number() {
+                Some(number) if !used_numbers.contains(&number) => {
+                    used_numbers.push(number);
+                    numbers.push(Some(number));
+                }
+                _ => numbers.push(None),
+            }
+        }
+
+        // Copy and renumber all partitions before modifying this list.
+        let mut copies = Vec::with_capacity(others.len());
+
+        for (partition, number) in others.iter().zip(numbers) {
+            let copy = partition.duplicate().and_then(|mut copy| {
+                if let (Some(original), None) = (copy.number(), number) {
+                    let free = (0..).find(|n| !used_numbers.contains(n)).unwrap();
+                    log::debug!(
+                        "PartitionList::merge renumbering partition {:?} to {:?}",
+                        original,
+                        free
+                    );
+                    copy.set_partition_number(free)?;
+                    used_numbers.push(free);
+                }
+
+                Ok(copy)
+            });
+
+            match copy {
+                Ok(copy) => copies.push(copy),
+                Err(e) => {
+                    let err_msg =
+                        format!("failed to merge partition {:?} {}", partition.number(), e);
+                    log::debug!("PartitionList::merge {}", err_msg);
+                    problems.push(LayoutProblem::Unexpected(err_msg));
+                }
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        let len = self.len();
+
+        for copy in copies {
+            let number = copy.number();
+
+            if let Err(e) = self.push(copy) {
+                let err_msg = format!("failed to merge partition {:?} {}", number, e);
+                log::debug!("PartitionList::merge {}", err_msg);
+                problems.push(LayoutProblem::Unexpected(err_msg));
+
+                // Remove the partitions merged so far.
+                while self.len() > len {
+                    self.pop();
+                }
+
+                break;
+            }
+        }
+
+        if problems.is_empty() {
+            log::debug!("PartitionList::merge merged partitions into list");
+
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
 }
 
 impl Index<usize> for PartitionList {
@@ -732,7 +1049,6 @@ fn partition_list_can_test_items_have_starting_sectors_not_in_increasing_order(
     }
 
     #[test]
-    #[ignore] // FIXME need upstream function modification
     fn partition_list_can_sort_by_starting_sector() -> crate::Result<()> {
         let partition1 = Partition::builder().number(1).starting_sector(64).build()?;
         let partition2 = Partition::builder()
@@ -749,7 +1065,7 @@ fn partition_list_can_sort_by_starting_sector() -> crate::Result<()> {
         list.push(partition2)?;
         list.push(partition1)?;
 
-        //list.sort_by(|p1, p2| p1.compare_starting_sectors(p2))?;
+        list.sort_by(|p1, p2| p1.compare_starting_sectors(p2))?;
 
         let actual = list.get(0).and_then(|p| p.starting_sector());
         let expected = Some(64);
@@ -766,6 +1082,129 @@ fn partition_list_can_sort_by_starting_sector() -> crate::Result<()> {
         Ok(())
     }
 
+    #[test]
+    fn partition_list_can_sort_by_partition_number() -> crate::Result<()> {
+        let partition1 = Partition::builder()
+            .number(1)
+            .starting_sector(8192)
+            .build()?;
+        let partition2 = Partition::builder()
+            .number(2)
+            .starting_sector(4096)
+            .build()?;
+        let partition3 = Partition::builder().number(3).starting_sector(64).build()?;
+
+        let mut list = PartitionList::new()?;
+        list.push(partition2)?;
+        list.push(partition3)?;
+        list.push(partition1)?;
+
+        list.sort_by(|p1, p2| p1.compare_partition_numbers(p2))?;
+
+        let actual: Vec<_> = list.iter().map(|p| p.number()).collect();
+        let expected = vec![Some(1), Some(2), Some(3)];
+        assert_eq!(actual, expected);
+
+        // Reverse order.
+        list.sort_by(|p1, p2| p2.compare_partition_numbers(p1))?;
+
+        let actual: Vec<_> = list.iter().map(|p| p.number()).collect();
+        let expected = vec![Some(3), Some(2), Some(1)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_list_can_sort_from_within_a_comparator() -> crate::Result<()> {
+        let mut outer = PartitionList::new()?;
+        for (number, start) in [(2, 4096), (3, 8192), (1, 64)] {
+            outer.push(
+                Partition::builder()
+                    .number(number)
+                    .starting_sector(start)
+                    .build()?,
+            )?;
+        }
+
+        let mut inner = PartitionList::new()?;
+        for (number, start) in [(1, 8192), (3, 64), (2, 4096)] {
+            inner.push(
+                Partition::builder()
+                    .number(number)
+                    .starting_sector(start)
+                    .build()?,
+            )?;
+        }
+
+        let mut nested_sorts = 0;
+        outer.sort_by(|p1, p2| {
+            // Alternate the inner sort order, to check each comparator is called by the right sort.
+            if nested_sorts % 2 == 0 {
+                inner
+                    .sort_by(|q1, q2| q1.compare_starting_sectors(q2))
+                    .unwrap();
+            } else {
+                inner
+                    .sort_by(|q1, q2| q1.compare_partition_numbers(q2))
+                    .unwrap();
+            }
+            nested_sorts += 1;
+
+            p1.compare_partition_numbers(p2)
+        })?;
+
+        assert!(nested_sorts > 0);
+
+        let actual: Vec<_> = outer.iter().map(|p| p.number()).collect();
+        let expected = vec![Some(1), Some(2), Some(3)];
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = inner.iter().map(|p| p.starting_sector()).collect();
+        // The last inner sort was by starting sector if `nested_sorts` is odd, by partition number
+        // otherwise.
+        let expected = if nested_sorts % 2 == 1 {
+            vec![Some(64), Some(4096), Some(8192)]
+        } else {
+            vec![Some(8192), Some(4096), Some(64)]
+        };
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_list_resumes_a_panic_raised_by_a_comparator() -> crate::Result<()> {
+        let mut list = PartitionList::new()?;
+        for (number, start) in [(2, 4096), (3, 8192), (1, 64)] {
+            list.push(
+                Partition::builder()
+                    .number(number)
+                    .starting_sector(start)
+                    .build()?,
+            )?;
+        }
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|_, _| panic!("comparator panicked"))
+        }));
+        let actual = result
+            .unwrap_err()
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string());
+        let expected = Some("comparator panicked".to_owned());
+        assert_eq!(actual, expected);
+
+        // The list can still be sorted after a panic.
+        list.sort_by(|p1, p2| p1.compare_partition_numbers(p2))?;
+
+        let actual: Vec<_> = list.iter().map(|p| p.number()).collect();
+        let expected = vec![Some(1), Some(2), Some(3)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn partition_list_can_iterate_forwards_over_an_empty_list() -> crate::Result<()> {
         let list = PartitionList::new()?;
@@ -1256,4 +1695,148 @@ fn partition_list_can_mutably_index_into_a_list() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn partition_list_can_merge_two_layouts() -> crate::Result<()> {
+        let mut base = PartitionList::new()?;
+        let partition = Partition::builder()
+            .number(0)
+            .starting_sector(2_048)
+            .size_in_sectors(2_048)
+            .build()?;
+        base.push(partition)?;
+
+        let mut data = PartitionList::new()?;
+        let partition = Partition::builder()
+            .number(0)
+            .starting_sector(4_096)
+            .size_in_sectors(2_048)
+            .build()?;
+        data.push(partition)?;
+
+        base.merge(&data).unwrap();
+
+        let actual = base.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        // The merged partition was renumbered.
+        let actual = base.get(1).and_then(|partition| partition.number());
+        let expected = Some(1);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_list_can_merge_a_layout_without_renumbering_collisions() -> crate::Result<()> {
+        let mut base = PartitionList::new()?;
+        let partition = Partition::builder()
+            .number(0)
+            .starting_sector(2_048)
+            .size_in_sectors(2_048)
+            .build()?;
+        base.push(partition)?;
+
+        // Partition 0 collides with the base's, it must not take partition 1's number.
+        let mut data = PartitionList::new()?;
+        for (number, start) in [(0, 4_096), (1, 6_144)] {
+            let partition = Partition::builder()
+                .number(number)
+                .starting_sector(start)
+                .size_in_sectors(2_048)
+                .build()?;
+            data.push(partition)?;
+        }
+
+        base.merge(&data).unwrap();
+
+        let actual: Vec<_> = base
+            .iter()
+            .map(|partition| (partition.number(), partition.starting_sector()))
+            .collect();
+        let expected = vec![
+            (Some(0), Some(2_048)),
+            (Some(2), Some(4_096)),
+            (Some(1), Some(6_144)),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_list_can_not_merge_overlapping_layouts() -> crate::Result<()> {
+        let mut base = PartitionList::new()?;
+        let partition = Partition::builder()
+            .number(0)
+            .starting_sector(2_048)
+            .size_in_sectors(4_096)
+            .build()?;
+        base.push(partition)?;
+
+        let mut data = PartitionList::new()?;
+        let partition = Partition::builder()
+            .number(1)
+            .starting_sector(4_096)
+            .size_in_sectors(4_096)
+            .build()?;
+        data.push(partition)?;
+
+        let actual = base.merge(&data);
+        let expected = Err(vec![LayoutProblem::Overlap {
+            first: Some(0),
+            second: Some(1),
+            start: 4_096,
+            end: 6_143,
+        }]);
+        assert_eq!(actual, expected);
+
+        let actual = base.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_list_fingerprint_does_not_depend_on_item_order() -> crate::Result<()> {
+        let build = |number: usize, start: u64| -> crate::Result<Partition> {
+            let partition = Partition::builder()
+                .number(number)
+                .starting_sector(start)
+                .size_in_sectors(2048)
+                .partition_type(PartitionKind::builder().guid(Guid::LinuxData).build()?)
+                .name("data")
+                .build()?;
+
+            Ok(partition)
+        };
+
+        let mut list = PartitionList::new()?;
+        list.push(build(0, 2048)?)?;
+        list.push(build(1, 4096)?)?;
+
+        let mut reordered = PartitionList::new()?;
+        reordered.push(build(1, 4096)?)?;
+        reordered.push(build(0, 2048)?)?;
+
+        let actual = list.fingerprint();
+        let expected = reordered.fingerprint();
+        assert_eq!(actual, expected);
+
+        let mut moved = PartitionList::new()?;
+        moved.push(build(0, 2048)?)?;
+        moved.push(build(1, 6144)?)?;
+
+        let actual = list.fingerprint();
+        let unexpected = moved.fingerprint();
+        assert_ne!(actual, unexpected);
+
+        let actual = PartitionList::new()?.fingerprint();
+        let expected = 0xcbf2_9ce4_8422_2325;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,105 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use typed_builder::TypedBuilder;
+
+// From standard library
+
+// From this library
+use crate::core::partition::PartitionSpec;
+
+#[derive(Debug, TypedBuilder)]
+#[builder(
+    builder_type(
+        name = PartitionSpecBuilder,
+        vis = "pub",
+        doc ="Configure and instantiate a [`PartitionSpec`].\n\nFor usage, see [`PartitionSpecBuilder::build`]."),
+    build_method(vis = "", name = __make))]
+pub(crate) struct PartSpecBuilder {
+    #[builder(setter(
+        doc = "Set the partition's identification number (the first partition has number `0`)."
+    ))]
+    number: usize,
+
+    #[builder(setter(doc = "Set the address of the partition's first sector."))]
+    starting_sector: u64,
+
+    #[builder(setter(doc = "Set the partition's size in sectors."))]
+    size_in_sectors: u64,
+
+    #[builder(
+        default,
+        setter(
+            into,
+            strip_option(fallback = partition_type_opt),
+            doc = "Set the partition's type identifier, i.e. the partition type's UUID for a `GPT`, or
+its hexadecimal code (e.g. `\"83\"`) for other partition tables."
+        )
+    )]
+    partition_type: Option<String>,
+
+    #[builder(
+        default,
+        setter(
+            into,
+            strip_option(fallback = name_opt),
+            doc = "Set the partition's name."
+        )
+    )]
+    name: Option<String>,
+
+    #[builder(
+        default,
+        setter(
+            into,
+            strip_option(fallback = uuid_opt),
+            doc = "Set the partition's UUID."
+        )
+    )]
+    uuid: Option<String>,
+
+    #[builder(
+        default,
+        setter(
+            into,
+            strip_option(fallback = attributes_opt),
+            doc = "Set the partition's attribute bits, in the same format as
+[`Partition::attribute_bits`](crate::core::partition::Partition::attribute_bits)."
+        )
+    )]
+    attributes: Option<String>,
+}
+
+#[allow(non_camel_case_types)]
+impl<
+        __partition_type: ::typed_builder::Optional<Option<String>>,
+        __name: ::typed_builder::Optional<Option<String>>,
+        __uuid: ::typed_builder::Optional<Option<String>>,
+        __attributes: ::typed_builder::Optional<Option<String>>,
+    >
+    PartitionSpecBuilder<(
+        (usize,),
+        (u64,),
+        (u64,),
+        __partition_type,
+        __name,
+        __uuid,
+        __attributes,
+    )>
+{
+    /// Completes a [`PartitionSpec`]'s configuration process, and creates a new instance.
+    pub fn build(self) -> PartitionSpec {
+        let builder = self.__make();
+
+        PartitionSpec::new(
+            builder.number,
+            builder.starting_sector,
+            builder.size_in_sectors,
+            builder.partition_type,
+            builder.name,
+            builder.uuid,
+            builder.attributes,
+        )
+    }
+}
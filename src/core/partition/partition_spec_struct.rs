@@ -0,0 +1,122 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::PartSpecBuilder;
+use crate::core::partition::PartitionSpecBuilder;
+
+/// Portable description of a partition, part of a
+/// [`DiskLayout`](crate::core::partition_table::DiskLayout).
+///
+/// Unlike a [`Partition`](crate::core::partition::Partition), a `PartitionSpec` is a plain Rust
+/// value, independent of `libfdisk`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartitionSpec {
+    number: usize,
+    starting_sector: u64,
+    size_in_sectors: u64,
+    partition_type: Option<String>,
+    name: Option<String>,
+    uuid: Option<String>,
+    attributes: Option<String>,
+}
+
+impl PartitionSpec {
+    #[doc(hidden)]
+    /// Creates a new `PartitionSpec`.
+    pub(crate) fn new(
+        number: usize,
+        starting_sector: u64,
+        size_in_sectors: u64,
+        partition_type: Option<String>,
+        name: Option<String>,
+        uuid: Option<String>,
+        attributes: Option<String>,
+    ) -> PartitionSpec {
+        log::debug!("PartitionSpec::new creating a new `PartitionSpec` instance");
+
+        Self {
+            number,
+            starting_sector,
+            size_in_sectors,
+            partition_type,
+            name,
+            uuid,
+            attributes,
+        }
+    }
+
+    /// Creates a [`PartitionSpecBuilder`] to configure and construct a new `PartitionSpec`
+    /// instance.
+    ///
+    /// Call the [`PartitionSpecBuilder`]'s
+    /// [`build()`](crate::core::partition::PartitionSpecBuilder::build) method to instantiate a
+    /// new `PartitionSpec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsfdisk::partition::PartitionSpec;
+    ///
+    /// let spec = PartitionSpec::builder()
+    ///     .number(0)
+    ///     .starting_sector(2048)
+    ///     .size_in_sectors(4096)
+    ///     .partition_type("0FC63DAF-8483-4772-8E79-3D69D8477DE4")
+    ///     .name("data")
+    ///     .build();
+    ///
+    /// assert_eq!(spec.name(), Some("data"));
+    /// assert_eq!(spec.uuid(), None);
+    /// ```
+    pub fn builder() -> PartitionSpecBuilder {
+        log::debug!("PartitionSpec::builder creating a new `PartitionSpecBuilder` instance");
+
+        PartSpecBuilder::builder()
+    }
+
+    /// Returns the partition's identification number.
+    ///
+    /// **Note:** the first partition has identification number `0`.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// Returns the address of the partition's first sector.
+    pub fn starting_sector(&self) -> u64 {
+        self.starting_sector
+    }
+
+    /// Returns the partition's size in sectors.
+    pub fn size_in_sectors(&self) -> u64 {
+        self.size_in_sectors
+    }
+
+    /// Returns the partition's type identifier, i.e. a UUID for a `GPT`, or a hexadecimal code for
+    /// other partition tables.
+    pub fn partition_type(&self) -> Option<&str> {
+        self.partition_type.as_deref()
+    }
+
+    /// Returns the partition's name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the partition's UUID.
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_deref()
+    }
+
+    /// Returns the partition's attribute bits, in the same format as
+    /// [`Partition::attribute_bits`](crate::core::partition::Partition::attribute_bits).
+    pub fn attributes(&self) -> Option<&str> {
+        self.attributes.as_deref()
+    }
+}
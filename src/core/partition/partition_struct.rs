@@ -304,6 +304,48 @@ pub(crate) fn set_uuid(&mut self, uuid: String) -> Result<(), PartitionError> {
         }
     }
 
+    #[doc(hidden)]
+    /// Creates a new `Partition` with the same type, name, UUID, attribute bits, identification
+    /// number, starting sector, and size as this one.
+    ///
+    /// A `libfdisk` partition can only belong to one table at a time, copies are needed to add
+    /// the same entry to another [`PartitionList`](crate::core::partition::PartitionList).
+    pub(crate) fn duplicate(&self) -> Result<Partition, PartitionError> {
+        log::debug!("Partition::duplicate copying partition");
+
+        let mut copy = Partition::new()?;
+
+        if let Some(kind) = self.partition_type() {
+            copy.set_partition_type(kind)?;
+        }
+
+        if let Some(name) = self.name() {
+            copy.set_name(name.to_owned())?;
+        }
+
+        if let Some(uuid) = self.uuid() {
+            copy.set_uuid(uuid.to_owned())?;
+        }
+
+        if let Some(bits) = self.attribute_bits() {
+            copy.set_attribute_bits(bits)?;
+        }
+
+        if let Some(number) = self.number() {
+            copy.set_partition_number(number)?;
+        }
+
+        if let Some(start) = self.starting_sector() {
+            copy.set_starting_sector(start)?;
+        }
+
+        if let Some(size) = self.size_in_sectors() {
+            copy.set_size_in_sectors(size)?;
+        }
+
+        Ok(copy)
+    }
+
     #[doc(hidden)]
     /// Set the partition's identification number to the first available. Set to `true` by default.
     pub(crate) fn use_first_free_partition_number(
@@ -479,6 +521,27 @@ pub fn ending_sector(&self) -> Option<u64> {
         }
     }
 
+    /// Returns the address of this `Partition`'s last sector if it is set, otherwise computes it
+    /// from the given `start` sector and `size` in sectors.
+    ///
+    /// **Note:** the ending sector is *inclusive*, i.e. it is the address of the last sector
+    /// belonging to the partition, not of the first sector after it. Thus, a partition starting
+    /// at sector `2048` with a size of `4096` sectors ends at sector `2048 + 4096 - 1 = 6143`.
+    /// The computed value saturates at `u64::MAX`, and is `start - 1` (or `0`) when `size` is
+    /// `0`.
+    pub fn ending_sector_or_computed(&self, start: u64, size: u64) -> u64 {
+        let last_sector = self.ending_sector().unwrap_or_else(|| match size {
+            0 => start.saturating_sub(1),
+            size => start.saturating_add(size - 1),
+        });
+        log::debug!(
+            "Partition::ending_sector_or_computed last partition sector: {:?}",
+            last_sector
+        );
+
+        last_sector
+    }
+
     /// Returns this `Partition`'s name.
     pub fn name(&self) -> Option<&str> {
         log::debug!("Partition::name getting partition name");
@@ -1245,4 +1308,46 @@ fn partition_can_unset_a_partition_starting_sector() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn partition_can_compute_an_unset_ending_sector() -> crate::Result<()> {
+        let partition = Partition::builder().build()?;
+
+        let actual = partition.ending_sector();
+        assert!(actual.is_none());
+
+        let actual = partition.ending_sector_or_computed(2048, 4096);
+        let expected = 6143;
+        assert_eq!(actual, expected);
+
+        let partition = Partition::builder()
+            .starting_sector(64)
+            .size_in_sectors(128)
+            .build()?;
+
+        let actual = partition.ending_sector_or_computed(2048, 4096);
+        let expected = 191;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_can_compute_an_unset_ending_sector_without_overflowing() -> crate::Result<()> {
+        let partition = Partition::builder().build()?;
+
+        let actual = partition.ending_sector_or_computed(u64::MAX, u64::MAX);
+        let expected = u64::MAX;
+        assert_eq!(actual, expected);
+
+        let actual = partition.ending_sector_or_computed(u64::MAX - 1, 1);
+        let expected = u64::MAX - 1;
+        assert_eq!(actual, expected);
+
+        let actual = partition.ending_sector_or_computed(0, 0);
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
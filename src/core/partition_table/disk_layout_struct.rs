@@ -0,0 +1,66 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::PartitionSpec;
+use crate::core::partition_table::PartitionTableKind;
+
+/// Portable description of a partition table and its partitions, as produced by
+/// [`Fdisk::export_layout`](crate::fdisk::Fdisk::export_layout), and consumed by
+/// [`Fdisk::import_layout`](crate::fdisk::Fdisk::import_layout).
+///
+/// A `DiskLayout` is a library-native alternative to a [`Script`](crate::core::script::Script),
+/// independent of `libfdisk`'s text format. With the `serde` feature enabled, it can be
+/// serialized to, and deserialized from, any format supported by `serde`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskLayout {
+    label_kind: PartitionTableKind,
+    disk_id: Option<String>,
+    sector_size: u64,
+    partitions: Vec<PartitionSpec>,
+}
+
+impl DiskLayout {
+    /// Creates a new `DiskLayout`.
+    pub fn new(
+        label_kind: PartitionTableKind,
+        disk_id: Option<String>,
+        sector_size: u64,
+        partitions: Vec<PartitionSpec>,
+    ) -> DiskLayout {
+        log::debug!("DiskLayout::new creating a new `DiskLayout` instance");
+
+        Self {
+            label_kind,
+            disk_id,
+            sector_size,
+            partitions,
+        }
+    }
+
+    /// Returns the partition table's type.
+    pub fn label_kind(&self) -> PartitionTableKind {
+        self.label_kind
+    }
+
+    /// Returns the partition table's identifier (e.g. a disk GUID for `GPT` partition tables),
+    /// or `None` if it has none.
+    pub fn disk_id(&self) -> Option<&str> {
+        self.disk_id.as_deref()
+    }
+
+    /// Returns the size of a logical sector in bytes.
+    pub fn sector_size(&self) -> u64 {
+        self.sector_size
+    }
+
+    /// Returns the description of each partition, in increasing order of partition number.
+    pub fn partitions(&self) -> &[PartitionSpec] {
+        &self.partitions
+    }
+}
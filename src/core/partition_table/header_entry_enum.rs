@@ -7,6 +7,7 @@
 // From standard library
 
 // From this library
+use crate::core::partition_table::PartitionTableKind;
 
 // FIXME discriminant values assigned more than once
 // I suppose *_LABELITEM_* should be monotonically increasing judging by the definition of
@@ -143,4 +144,18 @@ pub fn to_original_u32(&self) -> u32 {
 
         value - offset
     }
+
+    /// Returns the type of partition table whose header contains this entry, or `None` for
+    /// entries shared by all partition tables (i.e. [`HeaderEntry::GenericId`]).
+    pub fn partition_table_kind(&self) -> Option<PartitionTableKind> {
+        let value: u32 = (*self).into();
+
+        match value - self.to_original_u32() {
+            BSD_OFFSET => Some(PartitionTableKind::BSD),
+            GPT_OFFSET => Some(PartitionTableKind::GPT),
+            SGI_OFFSET => Some(PartitionTableKind::SGI),
+            SUN_OFFSET => Some(PartitionTableKind::SUN),
+            _ => None,
+        }
+    }
 }
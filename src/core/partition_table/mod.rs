@@ -9,6 +9,7 @@
 
 // From this library
 
+pub use disk_layout_struct::DiskLayout;
 pub use field_enum::Field;
 pub use field_format_struct::FieldFormat;
 pub use header_entry_content_struct::HeaderEntryContent;
@@ -19,11 +20,14 @@
 pub use partition_table_gpt_ext_trait::PartitionTableGPTExt;
 pub use partition_table_kind_enum::PartitionTableKind;
 pub use partition_table_struct::PartitionTable;
+pub use partition_table_summary_struct::PartitionTableSummary;
 pub use range_struct::Range;
 pub use shortcut_struct::Shortcut;
 pub use table_section_struct::TableSection;
+pub use verification_report_struct::VerificationReport;
 pub use verification_status_enum::VerificationStatus;
 
+mod disk_layout_struct;
 mod field_enum;
 mod field_format_struct;
 mod header_entry_content_struct;
@@ -34,7 +38,9 @@
 mod partition_table_gpt_ext_trait;
 mod partition_table_kind_enum;
 mod partition_table_struct;
+mod partition_table_summary_struct;
 mod range_struct;
 mod shortcut_struct;
 mod table_section_struct;
+mod verification_report_struct;
 mod verification_status_enum;
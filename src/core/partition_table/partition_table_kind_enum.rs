@@ -12,6 +12,7 @@
 
 /// Supported types of partition tables.
 #[derive(Clone, Copy, Debug, Eq, IntoPrimitive, PartialEq, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 #[non_exhaustive]
 pub enum PartitionTableKind {
@@ -9,6 +9,8 @@
 // From this library
 use crate::core::errors::PartitionTableError;
 
+use crate::core::partition::Code;
+use crate::core::partition::Guid;
 use crate::core::partition::PartitionKind;
 use crate::core::partition_table::Field;
 use crate::core::partition_table::FieldFormat;
@@ -311,6 +313,22 @@ pub fn partition_field_format_by_name<T>(&self, name: T) -> Option<FieldFormat>
         }
     }
 
+    /// Returns the [`Field`] displayed in the column named `name` (e.g. `"Start"`, `"Type-UUID"`),
+    /// or `None` if this `PartitionTable` has no such column.
+    ///
+    /// Column names are matched case-insensitively, as in `fdisk --list --output`.
+    pub fn field_by_name<T>(&self, name: T) -> Option<Field>
+    where
+        T: AsRef<str>,
+    {
+        let field = self
+            .partition_field_format_by_name(name)
+            .map(|format| format.field());
+        log::debug!("PartitionTable::field_by_name value: {:?}", field);
+
+        field
+    }
+
     /// Returns the number of partition types supported by this `PartitionTable`.
     pub fn count_supported_partition_types(&self) -> usize {
         let count = unsafe { libfdisk::fdisk_label_get_nparttypes(self.inner) };
@@ -570,7 +588,7 @@ pub fn partition_type_from_string<T>(
         })?;
 
         log::debug!(
-            "PartitionTable::partition_type_from_code converting string: {:?} to partition type",
+            "PartitionTable::partition_type_from_string converting string: {:?} to partition type",
             string
         );
 
@@ -585,12 +603,12 @@ pub fn partition_type_from_string<T>(
         match unsafe { ptr.assume_init() } {
             ptr if ptr.is_null() => {
                 let err_msg = format!("failed to convert string: {:?} to partition type", string);
-                log::debug!("PartitionTable::partition_type_from_code {}. libfdisk::fdisk_label_get_parttype_from_string returned a NULL pointer", err_msg);
+                log::debug!("PartitionTable::partition_type_from_string {}. libfdisk::fdisk_label_get_parttype_from_string returned a NULL pointer", err_msg);
 
                 Err(PartitionTableError::Parse(err_msg))
             }
             ptr => {
-                log::debug!("PartitionTable::partition_type_from_code converted string: {:?} to partition type", string);
+                log::debug!("PartitionTable::partition_type_from_string converted string: {:?} to partition type", string);
                 let kind = PartitionKind::from_ptr(ptr);
 
                 Ok(kind)
@@ -598,6 +616,33 @@ pub fn partition_type_from_string<T>(
         }
     }
 
+    /// Converts a [`Guid`] into a [`PartitionKind`], or returns `None` if this `PartitionTable`
+    /// does not support the partition type.
+    ///
+    /// **Note:** only `GPT` partition tables identify partition types by [`Guid`].
+    pub fn guid_type(&self, guid: Guid) -> Option<PartitionKind> {
+        log::debug!(
+            "PartitionTable::guid_type converting GUID: {:?} to partition type",
+            guid
+        );
+
+        self.partition_type_from_string(guid.as_str()).ok()
+    }
+
+    /// Converts a [`Code`] into a [`PartitionKind`], or returns `None` if this `PartitionTable`
+    /// does not support the partition type.
+    ///
+    /// **Note:** only `MBR`, `SGI`, and `SUN` partition tables identify partition types by
+    /// [`Code`].
+    pub fn code_type(&self, code: Code) -> Option<PartitionKind> {
+        log::debug!(
+            "PartitionTable::code_type converting code: {:?} to partition type",
+            code
+        );
+
+        self.partition_type_from_code(code.to_u32()).ok()
+    }
+
     /// `PartitionTable` keeps track of changes, so calling this function is not required unless
     /// you want to force an [`Fdisk`](crate::fdisk::Fdisk) instance to use the current state of
     /// this `PartitionTable` when writing data to disk.
@@ -0,0 +1,92 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition_table::PartitionTableKind;
+
+/// Machine-readable summary of a partition table's header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PartitionTableSummary {
+    kind: PartitionTableKind,
+    id: Option<String>,
+    bytes_per_logical_sector: u64,
+    bytes_per_physical_sector: u64,
+    first_usable_lba: u64,
+    last_usable_lba: u64,
+    max_partitions: usize,
+    partition_count: usize,
+}
+
+impl PartitionTableSummary {
+    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        kind: PartitionTableKind,
+        id: Option<String>,
+        bytes_per_logical_sector: u64,
+        bytes_per_physical_sector: u64,
+        first_usable_lba: u64,
+        last_usable_lba: u64,
+        max_partitions: usize,
+        partition_count: usize,
+    ) -> PartitionTableSummary {
+        log::debug!("PartitionTableSummary::new creating a new `PartitionTableSummary` instance");
+
+        Self {
+            kind,
+            id,
+            bytes_per_logical_sector,
+            bytes_per_physical_sector,
+            first_usable_lba,
+            last_usable_lba,
+            max_partitions,
+            partition_count,
+        }
+    }
+
+    /// Returns the partition table's type.
+    pub fn kind(&self) -> PartitionTableKind {
+        self.kind
+    }
+
+    /// Returns the partition table's identifier (e.g. a disk GUID for `GPT` partition tables),
+    /// or `None` if it has none.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the size of a logical sector in bytes.
+    pub fn bytes_per_logical_sector(&self) -> u64 {
+        self.bytes_per_logical_sector
+    }
+
+    /// Returns the size of a physical sector in bytes.
+    pub fn bytes_per_physical_sector(&self) -> u64 {
+        self.bytes_per_physical_sector
+    }
+
+    /// Returns the address of the first logical block usable by partitions.
+    pub fn first_usable_lba(&self) -> u64 {
+        self.first_usable_lba
+    }
+
+    /// Returns the address of the last logical block usable by partitions.
+    pub fn last_usable_lba(&self) -> u64 {
+        self.last_usable_lba
+    }
+
+    /// Returns the maximum number of entries the partition table can hold.
+    pub fn max_partitions(&self) -> usize {
+        self.max_partitions
+    }
+
+    /// Returns the number of partitions in the partition table.
+    pub fn partition_count(&self) -> usize {
+        self.partition_count
+    }
+}
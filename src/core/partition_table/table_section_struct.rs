@@ -52,16 +52,17 @@ pub(crate) fn new(name: String, starting_offset: u64, size: usize) -> TableSecti
         }
     }
 
+    /// Returns the name of this section.
     pub fn name(&self) -> &str {
         &self.name
     }
 
-    // FIXME offset in bytes or sectors?
+    /// Returns the offset, in bytes, of this section from the start of the device.
     pub fn starting_offset(&self) -> u64 {
         self.starting_offset
     }
 
-    // FIXME size in bytes or sectors?
+    /// Returns the size of this section in bytes.
     pub fn size(&self) -> usize {
         self.size
     }
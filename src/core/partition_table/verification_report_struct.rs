@@ -0,0 +1,49 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// Outcome of a partition table verification.
+///
+/// Holds the number of problems found, and the messages describing them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationReport {
+    issue_count: usize,
+    messages: Vec<String>,
+}
+
+impl VerificationReport {
+    #[doc(hidden)]
+    /// Creates a new `VerificationReport`.
+    pub(crate) fn new(issue_count: usize, messages: Vec<String>) -> VerificationReport {
+        let report = Self {
+            issue_count,
+            messages,
+        };
+        log::debug!(
+            "VerificationReport::new created a new `VerificationReport` instance: {:?}",
+            report
+        );
+
+        report
+    }
+
+    /// Returns the number of problems found in the partition table.
+    pub fn issue_count(&self) -> usize {
+        self.issue_count
+    }
+
+    /// Returns the warning and information messages emitted while verifying the partition table.
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// Returns `true` if the partition table has at least one problem.
+    pub fn has_issues(&self) -> bool {
+        self.issue_count > 0
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// A header whose value differs between two [`Script`](crate::core::script::Script)s, as
+/// reported by [`ScriptDiff::changed_headers`](crate::core::script::ScriptDiff::changed_headers).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeaderChange {
+    name: String,
+    old_value: Option<String>,
+    new_value: Option<String>,
+}
+
+impl HeaderChange {
+    #[doc(hidden)]
+    /// Creates a new `HeaderChange`.
+    pub(crate) fn new(
+        name: String,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) -> HeaderChange {
+        log::debug!(
+            "HeaderChange::new creating a new `HeaderChange` instance for header: {:?}",
+            name
+        );
+
+        Self {
+            name,
+            old_value,
+            new_value,
+        }
+    }
+
+    /// Returns the header's name (e.g. `label`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the header's value in the script compared, or `None` if it is not defined there.
+    pub fn old_value(&self) -> Option<&str> {
+        self.old_value.as_deref()
+    }
+
+    /// Returns the header's value in the script compared to, or `None` if it is not defined
+    /// there.
+    pub fn new_value(&self) -> Option<&str> {
+        self.new_value.as_deref()
+    }
+}
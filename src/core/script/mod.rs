@@ -9,6 +9,10 @@
 
 // From this library
 
+pub use header_change_struct::HeaderChange;
+pub use script_diff_struct::ScriptDiff;
 pub use script_struct::Script;
 
+mod header_change_struct;
+mod script_diff_struct;
 mod script_struct;
@@ -0,0 +1,75 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::script::HeaderChange;
+
+/// Differences between two [`Script`](crate::core::script::Script)s, as computed by
+/// [`Script::diff`](crate::core::script::Script::diff).
+///
+/// Partitions are identified by their partition number. Entries without one are given the lowest
+/// partition numbers left unused by the other entries of their script, in order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScriptDiff {
+    changed_headers: Vec<HeaderChange>,
+    added_partitions: Vec<usize>,
+    removed_partitions: Vec<usize>,
+    modified_partitions: Vec<usize>,
+}
+
+impl ScriptDiff {
+    #[doc(hidden)]
+    /// Creates a new `ScriptDiff`.
+    pub(crate) fn new(
+        changed_headers: Vec<HeaderChange>,
+        added_partitions: Vec<usize>,
+        removed_partitions: Vec<usize>,
+        modified_partitions: Vec<usize>,
+    ) -> ScriptDiff {
+        let diff = Self {
+            changed_headers,
+            added_partitions,
+            removed_partitions,
+            modified_partitions,
+        };
+        log::debug!(
+            "ScriptDiff::new created a new `ScriptDiff` instance: {:?}",
+            diff
+        );
+
+        diff
+    }
+
+    /// Returns the headers whose value differs between the two scripts.
+    pub fn changed_headers(&self) -> &[HeaderChange] {
+        &self.changed_headers
+    }
+
+    /// Returns the numbers of the partitions only present in the other script.
+    pub fn added_partitions(&self) -> &[usize] {
+        &self.added_partitions
+    }
+
+    /// Returns the numbers of the partitions only present in this script.
+    pub fn removed_partitions(&self) -> &[usize] {
+        &self.removed_partitions
+    }
+
+    /// Returns the numbers of the partitions present in both scripts, but with a different
+    /// starting sector, size, type, name, or UUID.
+    pub fn modified_partitions(&self) -> &[usize] {
+        &self.modified_partitions
+    }
+
+    /// Returns `true` when both scripts describe the same headers and partitions.
+    pub fn is_empty(&self) -> bool {
+        self.changed_headers.is_empty()
+            && self.added_partitions.is_empty()
+            && self.removed_partitions.is_empty()
+            && self.modified_partitions.is_empty()
+    }
+}
@@ -10,17 +10,35 @@
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::os::fd::{FromRawFd, IntoRawFd};
+use std::os::unix::fs::FileExt;
 use std::path::Path;
 
 use libc::c_char;
 
 // From this library
 use crate::core::errors::ScriptError;
+use crate::core::partition::LayoutProblem;
 use crate::core::partition::PartitionList;
+use crate::core::partition_table::PartitionTableKind;
+use crate::core::script::HeaderChange;
+use crate::core::script::ScriptDiff;
 use crate::fdisk::Fdisk;
 
 use crate::ffi_utils;
 
+/// Names of the headers defined by `sfdisk` scripts, compared by [`Script::diff`].
+const SCRIPT_HEADERS: [&str; 9] = [
+    "label",
+    "label-id",
+    "device",
+    "unit",
+    "first-lba",
+    "last-lba",
+    "table-length",
+    "grain",
+    "sector-size",
+];
+
 /// `sfdisk`-compatible script.
 #[derive(Debug)]
 #[repr(transparent)]
@@ -68,16 +86,61 @@ pub(crate) unsafe fn mut_from_boxed_ptr<'a>(
         (raw_ptr, entry_ref)
     }
 
-    #[doc(hidden)]
-    #[allow(dead_code)]
-    /// Creates a new `Script` instance.
-    pub(crate) fn new(_: &'fdisk Fdisk, inner: *mut libfdisk::fdisk_script) -> Script<'fdisk> {
+    /// Creates a new, empty, `Script` associated with `fdisk`.
+    ///
+    /// Unlike [`Fdisk::script_new`], the returned `Script` is owned by the caller; it is
+    /// deallocated when dropped.
+    pub fn new(fdisk: &'fdisk Fdisk) -> Result<Script<'fdisk>, ScriptError> {
         log::debug!("Script::new creating a new `Script` instance");
 
-        Self {
-            inner,
-            _marker: PhantomData,
+        let mut ptr = MaybeUninit::<*mut libfdisk::fdisk_script>::zeroed();
+        unsafe {
+            ptr.write(libfdisk::fdisk_new_script(fdisk.inner));
         }
+
+        match unsafe { ptr.assume_init() } {
+            ptr if ptr.is_null() => {
+                let err_msg = "failed to create a new `Script` instance".to_owned();
+                log::debug!(
+                    "Script::new {}. libfdisk::fdisk_new_script returned a NULL pointer",
+                    err_msg
+                );
+
+                Err(ScriptError::Config(err_msg))
+            }
+            inner => {
+                log::debug!("Script::new created a new `Script` instance");
+
+                Ok(Self {
+                    inner,
+                    _marker: PhantomData,
+                })
+            }
+        }
+    }
+
+    /// Creates a new `Script` associated with `fdisk`, and imports the `sfdisk` dump at
+    /// `file_path`.
+    ///
+    /// The file is parsed immediately; a [`ScriptError::Read`] error gives the number and
+    /// content of the first line `libfdisk` could not parse.
+    pub fn new_from_file<T>(
+        fdisk: &'fdisk Fdisk,
+        file_path: T,
+    ) -> Result<Script<'fdisk>, ScriptError>
+    where
+        T: AsRef<Path>,
+    {
+        let file_path = file_path.as_ref();
+        log::debug!(
+            "Script::new_from_file creating a new `Script` instance from: {:?}",
+            file_path
+        );
+
+        let mut script = Self::new(fdisk)?;
+        script.import_file(file_path)?;
+
+        Ok(script)
     }
 
     #[doc(hidden)]
@@ -101,7 +164,18 @@ fn read_file(ptr: &mut Self, file: &mut File) -> Result<(), ScriptError> {
                 Ok(())
             }
             code => {
-                let err_msg = "failed to read file".to_owned();
+                // `libfdisk` increments its line counter before parsing a line, so it holds the
+                // number of the offending line when parsing fails.
+                let err_msg = match ptr.count_lines().filter(|&n| n > 0) {
+                    Some(line_number) => match Self::line_content(file, line_number) {
+                        Some(line) => format!(
+                            "failed to read file: parse error on line {}: {:?}",
+                            line_number, line
+                        ),
+                        None => format!("failed to read file: parse error on line {}", line_number),
+                    },
+                    None => "failed to read file".to_owned(),
+                };
                 log::debug!("Script::read_file {}. libfdisk::fdisk_script_read_file returned error code: {:?}", err_msg, code);
 
                 Err(ScriptError::Read(err_msg))
@@ -109,6 +183,33 @@ fn read_file(ptr: &mut Self, file: &mut File) -> Result<(), ScriptError> {
         }
     }
 
+    #[doc(hidden)]
+    /// Returns the content of line `line_number` (1-based) in `file`, or `None` if it can not be
+    /// read.
+    fn line_content(file: &File, line_number: usize) -> Option<String> {
+        // Read with positional reads, to leave the file offset shared with the caller untouched.
+        let mut content = Vec::new();
+        let mut buffer = [0u8; 4096];
+        let mut offset = 0;
+
+        loop {
+            match file.read_at(&mut buffer, offset) {
+                Ok(0) => break,
+                Ok(n) => {
+                    content.extend_from_slice(&buffer[..n]);
+                    offset += n as u64;
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => return None,
+            }
+        }
+
+        String::from_utf8_lossy(&content)
+            .lines()
+            .nth(line_number - 1)
+            .map(str::to_owned)
+    }
+
     /// Imports the file at `file_path`.
     pub fn import_file<T>(&mut self, file_path: T) -> Result<(), ScriptError>
     where
@@ -359,6 +460,211 @@ pub fn override_partition_table(&mut self, entries: PartitionList) -> Result<(),
         }
     }
 
+    /// Checks that the partitions described in this `Script` can be applied to the device
+    /// assigned to `fdisk`, i.e. that they:
+    /// - start after the device's first usable sector, and end before its last usable sector,
+    /// - start on a grain boundary (see [`Fdisk::device_grain_size`]),
+    /// - do not overlap each other (container partitions, like a `MBR` extended partition, are
+    ///   excluded from this check).
+    ///
+    /// Partitions without an explicit starting sector or size are not checked, since their
+    /// final location is only known once the `Script` is applied.
+    ///
+    /// The usable sectors are given by the `first-lba` and `last-lba` headers of this `Script`,
+    /// when present. Otherwise, they are those of a `GPT` created on the device if the `Script`'s
+    /// `label` is `gpt` and the device does not already have one, or those of the partition
+    /// table currently on the device.
+    ///
+    /// Returns a list of human-readable descriptions of the problems found, if any.
+    pub fn validate_for_device(&self, fdisk: &Fdisk) -> Result<(), Vec<String>> {
+        log::debug!("Script::validate_for_device validating script against device");
+
+        let entries = match self.partition_table_entries() {
+            Some(entries) => entries,
+            None => return Ok(()),
+        };
+
+        let header_number = |name: &str| {
+            self.header_value(name)
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let is_gpt_script = self
+            .header_value("label")
+            .map(|label| label.trim().eq_ignore_ascii_case("gpt"))
+            .unwrap_or(false);
+
+        let (default_first_lba, default_last_lba) =
+            if is_gpt_script && !fdisk.partition_table_is_of_type(PartitionTableKind::GPT) {
+                let entries = header_number("table-length")
+                    .and_then(|length| u32::try_from(length).ok())
+                    .unwrap_or(128);
+
+                Fdisk::gpt_usable_lba_bounds(fdisk, entries)
+            } else {
+                (fdisk.device_first_lba(), fdisk.device_last_lba())
+            };
+
+        let first_lba = header_number("first-lba").unwrap_or(default_first_lba);
+        let last_lba = header_number("last-lba").unwrap_or(default_last_lba);
+        let sector_size = fdisk.device_bytes_per_logical_sector().max(1);
+        let grain = (fdisk.device_grain_size() / sector_size).max(1);
+
+        let mut problems = Vec::new();
+
+        for (index, partition) in entries.iter().enumerate() {
+            let name = match partition.number() {
+                Some(number) => format!("partition {}", number + 1),
+                None => format!("entry {}", index + 1),
+            };
+
+            let (start, size) = match (partition.starting_sector(), partition.size_in_sectors()) {
+                (Some(start), Some(size)) if size > 0 => (start, size),
+                _ => continue,
+            };
+            let end = start.saturating_add(size - 1);
+
+            if start < first_lba {
+                problems.push(format!(
+                    "{} starts at sector {}, before the first usable sector {}",
+                    name, start, first_lba
+                ));
+            }
+
+            if end > last_lba {
+                problems.push(format!(
+                    "{} ends at sector {}, beyond the last usable sector {}",
+                    name, end, last_lba
+                ));
+            }
+
+            if start % grain != 0 {
+                problems.push(format!(
+                    "{} starts at sector {}, which is not aligned on a {} sector boundary",
+                    name, start, grain
+                ));
+            }
+        }
+
+        let describe = |number: Option<usize>| match number {
+            Some(number) => format!("partition {}", number + 1),
+            None => "unnumbered partition".to_owned(),
+        };
+
+        for problem in entries.overlaps() {
+            match problem {
+                LayoutProblem::Overlap {
+                    first,
+                    second,
+                    start,
+                    end,
+                } => problems.push(format!(
+                    "{} overlaps {} from sector {} to sector {}",
+                    describe(first),
+                    describe(second),
+                    start,
+                    end
+                )),
+                problem => problems.push(problem.to_string()),
+            }
+        }
+
+        if problems.is_empty() {
+            log::debug!("Script::validate_for_device script fits the device");
+
+            Ok(())
+        } else {
+            log::debug!(
+                "Script::validate_for_device script does not fit the device: {:?}",
+                problems
+            );
+
+            Err(problems)
+        }
+    }
+
+    /// Applies this `Script` to a virtual, in-memory, device of `device_sectors` sectors of
+    /// `sector_size` bytes, and returns the resulting list of partitions, or a list of
+    /// human-readable descriptions of the problems encountered.
+    ///
+    /// No real device is read or modified, and no special privileges are required, which makes
+    /// this method suitable to check `sfdisk` scripts in a CI pipeline. On top of the errors
+    /// reported while applying the script, the problems returned are those detected by
+    /// [`Script::validate_for_device`].
+    pub fn simulate(
+        &self,
+        device_sectors: u64,
+        sector_size: u32,
+    ) -> Result<PartitionList, Vec<String>> {
+        log::debug!(
+            "Script::simulate applying script to a virtual device of {:?} sectors of {:?} bytes",
+            device_sectors,
+            sector_size
+        );
+
+        let device_name = c"rsfdisk-simulate";
+        let fd = unsafe { libc::memfd_create(device_name.as_ptr(), libc::MFD_CLOEXEC) };
+        if fd < 0 {
+            let err_msg = format!(
+                "failed to create virtual device: {}",
+                io::Error::last_os_error()
+            );
+            log::debug!("Script::simulate {}", err_msg);
+
+            return Err(vec![err_msg]);
+        }
+
+        let device = unsafe { File::from_raw_fd(fd) };
+
+        let device_size = device_sectors
+            .checked_mul(sector_size as u64)
+            .ok_or_else(|| {
+                let err_msg = format!(
+                    "virtual device of {:?} sectors of {:?} bytes is too large",
+                    device_sectors, sector_size
+                );
+                log::debug!("Script::simulate {}", err_msg);
+
+                vec![err_msg]
+            })?;
+
+        device.set_len(device_size).map_err(|e| {
+            let err_msg = format!("failed to set virtual device size: {}", e);
+            log::debug!("Script::simulate {}", err_msg);
+
+            vec![err_msg]
+        })?;
+
+        let mut disk = Fdisk::builder()
+            .assign_device_by_file(device, "rsfdisk-simulate")
+            .device_sector_sizes(sector_size, sector_size)
+            .enable_read_write()
+            .build()
+            .map_err(|e| {
+                let err_msg = format!("failed to assign virtual device: {}", e);
+                log::debug!("Script::simulate {}", err_msg);
+
+                vec![err_msg]
+            })?;
+
+        if let Err(e) = disk.script_apply(self) {
+            let mut problems = self.validate_for_device(&disk).err().unwrap_or_default();
+            problems.push(e.to_string());
+            log::debug!("Script::simulate failed to apply script: {:?}", problems);
+
+            return Err(problems);
+        }
+
+        self.validate_for_device(&disk)?;
+
+        let partitions = disk.used_partitions().map_err(|e| vec![e.to_string()])?;
+        log::debug!(
+            "Script::simulate script yields {:?} partitions",
+            partitions.len()
+        );
+
+        Ok(partitions)
+    }
+
     #[doc(hidden)]
     /// Enables/Disables JSON output.
     fn json_output(ptr: &mut Self, enable: bool) -> Result<(), ScriptError> {
@@ -600,6 +906,90 @@ pub fn header_value<T>(&self, name: T) -> Option<&str>
         }
     }
 
+    /// Compares this `Script` to `other`, returning the headers whose value changed, and the
+    /// partitions added, removed, or modified in `other`.
+    ///
+    /// Only the headers defined by `sfdisk` are compared: `label`, `label-id`, `device`, `unit`,
+    /// `first-lba`, `last-lba`, `table-length`, `grain`, and `sector-size`. Partitions are
+    /// matched by partition number (entries without one get the lowest numbers left unused in
+    /// their script); a matched partition is modified if its starting sector, size, type, name,
+    /// or UUID differ.
+    pub fn diff(&self, other: &Script) -> ScriptDiff {
+        log::debug!("Script::diff comparing scripts");
+
+        let changed_headers = SCRIPT_HEADERS
+            .iter()
+            .filter_map(|&name| {
+                let old = self.header_value(name);
+                let new = other.header_value(name);
+
+                (old != new).then(|| {
+                    HeaderChange::new(
+                        name.to_owned(),
+                        old.map(String::from),
+                        new.map(String::from),
+                    )
+                })
+            })
+            .collect();
+
+        let records = |script: &Script| -> Vec<(usize, Vec<u8>)> {
+            let entries = match script.partition_table_entries() {
+                Some(entries) => entries,
+                None => return Vec::new(),
+            };
+
+            // Entries without a partition number get the lowest numbers left free by the others,
+            // so that they never collide with an explicitly numbered entry.
+            let mut used: Vec<usize> = entries.iter().filter_map(|p| p.number()).collect();
+            let mut next_free = 0;
+
+            entries
+                .iter()
+                .map(|partition| {
+                    let number = partition.number().unwrap_or_else(|| {
+                        while used.contains(&next_free) {
+                            next_free += 1;
+                        }
+                        used.push(next_free);
+
+                        next_free
+                    });
+
+                    (number, PartitionList::fingerprint_record(partition))
+                })
+                .collect()
+        };
+
+        let old_records = records(self);
+        let new_records = records(other);
+
+        let mut added_partitions = Vec::new();
+        let mut modified_partitions = Vec::new();
+        for (number, new_record) in new_records.iter() {
+            match old_records.iter().find(|(n, _)| n == number) {
+                Some((_, old_record)) if old_record != new_record => {
+                    modified_partitions.push(*number)
+                }
+                Some(_) => {}
+                None => added_partitions.push(*number),
+            }
+        }
+
+        let removed_partitions = old_records
+            .iter()
+            .filter(|(number, _)| !new_records.iter().any(|(n, _)| n == number))
+            .map(|(number, _)| *number)
+            .collect();
+
+        ScriptDiff::new(
+            changed_headers,
+            added_partitions,
+            removed_partitions,
+            modified_partitions,
+        )
+    }
+
     /// Returns `true` if the header `label` was defined.
     pub fn has_header_label(&self) -> bool {
         let state = unsafe { libfdisk::fdisk_script_has_force_label(self.inner) == 1 };
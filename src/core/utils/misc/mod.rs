@@ -10,6 +10,7 @@
 use std::path::Path;
 
 // From this library
+use crate::fdisk::SizeFormat;
 use crate::ffi_utils;
 
 /// Returns the name of a partition on a device from its identification number.
@@ -57,3 +58,105 @@ pub fn partition_name<T>(device_name: T, partition_number: usize) -> Option<Stri
         }
     }
 }
+
+/// Formats a size in bytes the same way `fdisk` does.
+///
+/// With [`SizeFormat::Bytes`], the size is printed as is (e.g. `"10485760"`). With
+/// [`SizeFormat::HumanReadable`], the size is printed with a binary prefix and up to two decimal
+/// digits, like `util-linux`'s `size_to_human_string` (e.g. `"512 B"`, `"1.5 KiB"`, `"8 GiB"`,
+/// `"931.51 GiB"`).
+///
+/// To format a size expressed in sectors, multiply it first by the device's logical sector size
+/// (see [`Fdisk::device_bytes_per_logical_sector`](crate::fdisk::Fdisk::device_bytes_per_logical_sector)).
+pub fn format_size(bytes: u64, format: SizeFormat) -> String {
+    match format {
+        SizeFormat::HumanReadable => human_readable_size(bytes),
+        SizeFormat::Bytes => bytes.to_string(),
+    }
+}
+
+#[doc(hidden)]
+/// Port of `util-linux`'s `size_to_human_string` with options `SIZE_DECIMAL_2DIGITS |
+/// SIZE_SUFFIX_SPACE | SIZE_SUFFIX_3LETTER`.
+fn human_readable_size(bytes: u64) -> String {
+    const LETTERS: [char; 7] = ['B', 'K', 'M', 'G', 'T', 'P', 'E'];
+
+    // Largest power of 1024 not greater than `bytes`.
+    let exp = (10..=60)
+        .step_by(10)
+        .find(|&shift| (bytes as u128) < (1u128 << shift))
+        .unwrap_or(70)
+        - 10;
+
+    let letter = LETTERS[exp / 10];
+    let suffix = if letter == 'B' {
+        String::from("B")
+    } else {
+        format!("{}iB", letter)
+    };
+
+    let mut dec = bytes >> exp;
+    let remainder = bytes & ((1u64 << exp) - 1);
+
+    // Keep three digits after the decimal point, then round to two.
+    let mut frac = ((remainder as u128 * 1000) >> exp) as u64;
+    frac = (frac + 5) / 10;
+
+    // Rounding might overflow.
+    if frac == 100 {
+        dec += 1;
+        frac = 0;
+    }
+
+    match frac {
+        0 => format!("{} {}", dec, suffix),
+        n if n % 10 == 0 => format!("{}.{} {}", dec, n / 10, suffix),
+        n => format!("{}.{:02} {}", dec, n, suffix),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format_size_prints_bytes_as_is() {
+        let actual = format_size(10_485_760, SizeFormat::Bytes);
+        let expected = "10485760";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn format_size_matches_util_linux_human_readable_sizes() {
+        let sizes = [
+            (0, "0 B"),
+            (512, "512 B"),
+            (1_023, "1023 B"),
+            (1_536, "1.5 KiB"),
+            (10_485_760, "10 MiB"),
+            (8_589_934_592, "8 GiB"),
+            (1_000_204_886_016, "931.51 GiB"),
+            (2_000_398_934_016, "1.82 TiB"),
+        ];
+
+        for (bytes, expected) in sizes {
+            let actual = format_size(bytes, SizeFormat::HumanReadable);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn partition_name_appends_a_p_suffix_to_names_ending_with_a_digit() {
+        let names = [
+            ("/dev/sda", 2, "/dev/sda2"),
+            ("/dev/nvme0n1", 2, "/dev/nvme0n1p2"),
+            ("/dev/loop0", 1, "/dev/loop0p1"),
+            ("/dev/mmcblk0", 3, "/dev/mmcblk0p3"),
+        ];
+
+        for (device_name, partition_number, expected) in names {
+            let actual = partition_name(device_name, partition_number);
+            assert_eq!(actual.as_deref(), Some(expected));
+        }
+    }
+}
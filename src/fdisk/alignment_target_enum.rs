@@ -0,0 +1,26 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// Boundary on which to align the starting sector of a new partition (see
+/// [`Fdisk::partition_add_aligned`](crate::fdisk::Fdisk::partition_add_aligned)).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AlignmentTarget {
+    /// Align on the device's grain size (usually `1 MiB`).
+    Grain,
+
+    /// Align on the device's physical sector size.
+    PhysicalSector,
+
+    /// Align on the device's optimal I/O size.
+    OptimalIo,
+
+    /// Align on a multiple of the given number of bytes (e.g. a RAID stripe size).
+    Bytes(u64),
+}
@@ -10,17 +10,34 @@
 use crate::fdisk::Fdisk;
 use crate::fdisk::FdiskError;
 
+use crate::core::partition_table::PartitionTableKind;
+
 /// `BSD` specific functions for a [`Fdisk`].
 ///
+/// A `BSD disklabel` usually lives inside a partition of a `DOS` partition table. To work on
+/// it, create a nested partitioner from the `DOS` parent with
+/// [`Fdisk::create_nested_partitioner_for`](crate::fdisk::Fdisk::create_nested_partitioner_for),
+/// then call the methods below on the nested partitioner.
+///
 /// This trait is sealed and can not be implemented for types outside of `rsfdisk`.
 pub trait FdiskBSDExt: Sealed {
-    /// Edits the fields of a `BSD disklabel`.
+    /// Edits the fields of a `BSD disklabel` by [`Prompt`](crate::core::prompt::Prompt)ing the
+    /// user for new values.
+    ///
+    /// Returns a [`FdiskError::DialogsDisabled`] error if interactive partitioning is disabled
+    /// (see [`FdiskBuilder::enable_interactive`](crate::fdisk::FdiskBuilder::enable_interactive)),
+    /// or a [`FdiskError::Config`] error if the partition table is not a `BSD disklabel`.
     fn bsd_edit_disk_label(&mut self) -> Result<(), FdiskError>;
 
     /// Sets a `DOS` partition to a nested `BSD` partition table as its parent.
+    ///
+    /// Returns a [`FdiskError::Config`] error if this `Fdisk` is not a nested partitioner, or if
+    /// its partition table is not a `BSD disklabel`.
     fn bsd_link_to_nested_partition(&mut self) -> Result<(), FdiskError>;
 
     /// Installs a `BSD` bootstrap file on the assigned device.
+    ///
+    /// Returns a [`FdiskError::Config`] error if the partition table is not a `BSD disklabel`.
     fn bsd_install_bootstrap_file(&mut self) -> Result<(), FdiskError>;
 }
 
@@ -28,6 +45,21 @@ impl<'a> FdiskBSDExt for Fdisk<'a> {
     fn bsd_edit_disk_label(&mut self) -> Result<(), FdiskError> {
         log::debug!("Fdisk::bsd_edit_disk_label editing BSD disklabel");
 
+        if !self.partition_table_is_of_type(PartitionTableKind::BSD) {
+            let err_msg = "failed to edit BSD disklabel. No BSD disklabel on device".to_owned();
+            log::debug!("Fdisk::bsd_edit_disk_label {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        if !self.is_partitioning_interactive() {
+            let err_msg =
+                "failed to edit BSD disklabel. Interactive partitioning is disabled".to_owned();
+            log::debug!("Fdisk::bsd_edit_disk_label {}", err_msg);
+
+            return Err(FdiskError::DialogsDisabled(err_msg));
+        }
+
         let result = unsafe { libfdisk::fdisk_bsd_edit_disklabel(self.inner) };
 
         match result {
@@ -50,6 +82,23 @@ fn bsd_link_to_nested_partition(&mut self) -> Result<(), FdiskError> {
             "Fdisk::bsd_link_to_nested_partition linking DOS parent to BSD nested partition table"
         );
 
+        if !self.partition_table_is_of_type(PartitionTableKind::BSD) {
+            let err_msg = "failed to link DOS parent to BSD nested partition table. No BSD disklabel on device".to_owned();
+            log::debug!("Fdisk::bsd_link_to_nested_partition {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let parent = unsafe { libfdisk::fdisk_get_parent(self.inner) };
+        if parent.is_null() {
+            let err_msg =
+                "failed to link DOS parent to BSD nested partition table. Not a nested partitioner"
+                    .to_owned();
+            log::debug!("Fdisk::bsd_link_to_nested_partition {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
         let result = unsafe { libfdisk::fdisk_bsd_link_partition(self.inner) };
 
         match result {
@@ -70,6 +119,15 @@ fn bsd_link_to_nested_partition(&mut self) -> Result<(), FdiskError> {
     fn bsd_install_bootstrap_file(&mut self) -> Result<(), FdiskError> {
         log::debug!("Fdisk::bsd_install_bootstrap_file installing BSD bootstrap file on device");
 
+        if !self.partition_table_is_of_type(PartitionTableKind::BSD) {
+            let err_msg =
+                "failed to install BSD bootstrap file on device. No BSD disklabel on device"
+                    .to_owned();
+            log::debug!("Fdisk::bsd_install_bootstrap_file {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
         let result = unsafe { libfdisk::fdisk_bsd_write_bootstrap(self.inner) };
 
         match result {
@@ -17,6 +17,16 @@ pub enum FdiskBuilderError {
     #[error(transparent)]
     Config(#[from] FdiskError),
 
+    /// Error if the path given to [`FdiskBuilder::assign_device`](crate::fdisk::FdiskBuilder::assign_device)
+    /// is a directory.
+    #[error("{0}")]
+    DeviceIsDirectory(String),
+
+    /// Error if the path given to [`FdiskBuilder::assign_device`](crate::fdisk::FdiskBuilder::assign_device)
+    /// does not exist.
+    #[error("{0}")]
+    DeviceNotFound(String),
+
     /// Error if two mutually exclusive setter functions are called.
     #[error("{0}")]
     MutuallyExclusive(String),
@@ -24,4 +34,9 @@ pub enum FdiskBuilderError {
     /// Error if a required function was not called.
     #[error("{0}")]
     Required(String),
+
+    /// Error if the path given to [`FdiskBuilder::assign_device`](crate::fdisk::FdiskBuilder::assign_device)
+    /// is neither a block device nor a regular file.
+    #[error("{0}")]
+    UnsupportedDeviceType(String),
 }
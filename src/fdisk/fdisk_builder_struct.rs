@@ -6,12 +6,16 @@
 
 // From standard library
 use std::fs::File;
+use std::io::ErrorKind;
+use std::os::unix::fs::FileTypeExt;
 use std::path::{Path, PathBuf};
 
 // From this library
+use crate::core::partition_table::PartitionTableKind;
 use crate::fdisk::DeviceAddressing;
 use crate::fdisk::Fdisk;
 use crate::fdisk::FdiskBuilderError;
+use crate::fdisk::FdiskError;
 use crate::fdisk::SizeFormat;
 
 #[derive(Debug, TypedBuilder)]
@@ -58,6 +62,19 @@ pub(crate) struct CtxBuilder {
 opened in read/write mode if you set [`FdiskBuilder::enable_read_write`] to `true`."))]
     assign_device_by_file: Option<(File, PathBuf)>,
 
+    #[builder(
+        default = true,
+        setter(
+            doc = "When set to `true` (default), resolve symbolic links in the path given to
+[`FdiskBuilder::assign_device`] before assigning the device. For example, a path like
+`/dev/disk/by-id/ata-...` is replaced by the device node it points to (e.g. `/dev/sda`), so that
+[`Fdisk::device_name`] and [`Fdisk::partition_device_path`] return stable kernel device names.\n\n
+Set to `false` to keep the path as given. This option has no effect on devices assigned with
+[`FdiskBuilder::assign_device_by_file`]."
+        )
+    )]
+    canonicalize_device_path: bool,
+
     #[builder(
         default,
         setter(
@@ -99,6 +116,19 @@ pub(crate) struct CtxBuilder {
     )]
     device_addressing: Option<DeviceAddressing>,
 
+    #[builder(
+        default,
+        setter(
+        transform = |kinds: &[PartitionTableKind]| Some(kinds.to_vec()),
+        doc = "Only recognize partition tables of the listed types when scanning the assigned
+device; all others are disabled. For example, this prevents an accidental `SUN` signature from being
+detected as a partition table.
+
+Partition tables are enabled/disabled **before** the device is assigned, which is when it is
+scanned. Thus, [`Fdisk::device_has_partition_table`] will return `false` on a device with a
+partition table of a type not in `kinds`."))]
+    enable_only_labels: Option<Vec<PartitionTableKind>>,
+
     #[builder(setter(
         strip_bool,
         doc = "Enable the dialog-driven partitioning process (interactive mode). Disabled by default."
@@ -153,10 +183,12 @@ impl<
         'a,
         __assign_device: ::typed_builder::Optional<Option<PathBuf>>,
         __assign_device_by_file: ::typed_builder::Optional<Option<(File, PathBuf)>>,
+        __canonicalize_device_path: ::typed_builder::Optional<bool>,
         __device_geometry: ::typed_builder::Optional<Option<(u32, u32, u32)>>,
         __device_grain_size: ::typed_builder::Optional<Option<u64>>,
         __device_sector_sizes: ::typed_builder::Optional<Option<(u32, u32)>>,
         __device_addressing: ::typed_builder::Optional<Option<DeviceAddressing>>,
+        __enable_only_labels: ::typed_builder::Optional<Option<Vec<PartitionTableKind>>>,
         __enable_interactive: ::typed_builder::Optional<bool>,
         __display_partition_list_only: ::typed_builder::Optional<bool>,
         __display_partition_details: ::typed_builder::Optional<bool>,
@@ -168,10 +200,12 @@ impl<
     FdiskBuilder<(
         __assign_device,
         __assign_device_by_file,
+        __canonicalize_device_path,
         __device_geometry,
         __device_grain_size,
         __device_sector_sizes,
         __device_addressing,
+        __enable_only_labels,
         __enable_interactive,
         __display_partition_list_only,
         __display_partition_details,
@@ -181,14 +215,129 @@ impl<
         __wipe_device_metadata,
     )>
 {
+    #[doc(hidden)]
+    /// Checks that `device_path` exists, and points to either a block device or a regular file.
+    fn validate_device_path(device_path: &Path) -> Result<(), FdiskBuilderError> {
+        let metadata = std::fs::metadata(device_path).map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                let err_msg = format!("device {:?} does not exist", device_path);
+                log::debug!("FdiskBuilder::validate_device_path {}", err_msg);
+
+                FdiskBuilderError::DeviceNotFound(err_msg)
+            } else {
+                let err_msg = format!("failed to read metadata of device {:?}. {}", device_path, e);
+                log::debug!("FdiskBuilder::validate_device_path {}", err_msg);
+
+                FdiskBuilderError::Config(FdiskError::AssignDevice(err_msg))
+            }
+        })?;
+
+        let file_type = metadata.file_type();
+
+        if file_type.is_dir() {
+            let err_msg = format!("device {:?} is a directory", device_path);
+            log::debug!("FdiskBuilder::validate_device_path {}", err_msg);
+
+            Err(FdiskBuilderError::DeviceIsDirectory(err_msg))
+        } else if file_type.is_block_device() || file_type.is_file() {
+            Ok(())
+        } else {
+            let err_msg = format!(
+                "device {:?} is neither a block device nor a regular file",
+                device_path
+            );
+            log::debug!("FdiskBuilder::validate_device_path {}", err_msg);
+
+            Err(FdiskBuilderError::UnsupportedDeviceType(err_msg))
+        }
+    }
+
+    #[doc(hidden)]
+    /// Resolves all symbolic links in `device_path`, returning the absolute path to the device.
+    fn resolve_device_path(device_path: &Path) -> Result<PathBuf, FdiskBuilderError> {
+        let canonical_path = std::fs::canonicalize(device_path).map_err(|e| {
+            let err_msg = format!(
+                "failed to canonicalize device path {:?}. {}",
+                device_path, e
+            );
+            log::debug!("FdiskBuilder::resolve_device_path {}", err_msg);
+
+            FdiskBuilderError::Config(FdiskError::AssignDevice(err_msg))
+        })?;
+
+        log::debug!(
+            "FdiskBuilder::resolve_device_path resolved {:?} to {:?}",
+            device_path,
+            canonical_path
+        );
+
+        Ok(canonical_path)
+    }
+
     /// Completes a [`Fdisk`]'s configuration process, and creates a new instance.
+    ///
+    /// When a device is assigned by path, `build` first checks that the path exists, and points
+    /// to either a block device or a regular file (e.g. a disk image). It returns a
+    /// [`FdiskBuilderError::DeviceNotFound`], [`FdiskBuilderError::DeviceIsDirectory`], or
+    /// [`FdiskBuilderError::UnsupportedDeviceType`] error otherwise. Unless
+    /// [`FdiskBuilder::canonicalize_device_path`] is set to `false`, symbolic links in the path
+    /// are then resolved.
     pub fn build(self) -> Result<Fdisk<'a>, FdiskBuilderError> {
         log::debug!("FdiskBuilder::build building a new `Fdisk` instance");
 
-        let builder = self.__make();
+        let mut builder = self.__make();
+
+        if let Some(device_path) = builder.assign_device.as_deref() {
+            Self::validate_device_path(device_path)?;
+        }
+
+        if builder.canonicalize_device_path {
+            builder.assign_device = builder
+                .assign_device
+                .as_deref()
+                .map(Self::resolve_device_path)
+                .transpose()?;
+        }
 
         let mut context = Fdisk::new()?;
 
+        // Select which partition tables to recognize.
+        // Must be done BEFORE any assign_device_* function is called.
+        if let Some(kinds) = builder.enable_only_labels {
+            for kind in [
+                PartitionTableKind::BSD,
+                PartitionTableKind::DOS,
+                PartitionTableKind::GPT,
+                PartitionTableKind::SGI,
+                PartitionTableKind::SUN,
+            ] {
+                if let Some(table) = context.partition_table_by_type_mut(kind) {
+                    if kinds.contains(&kind) {
+                        table.enable();
+                    } else {
+                        table.disable();
+                    }
+                }
+            }
+        }
+
+        // ----------------------------------------------------------------------------
+        // Override the device's preferred values.
+        // These overrides must be set BEFORE any assign_device_* function is called.
+
+        if let Some((cylinders, heads, sectors)) = builder.device_geometry {
+            context.save_device_geometry_overrides(cylinders, heads, sectors)?;
+        }
+
+        if let Some(grain_size) = builder.device_grain_size {
+            context.save_device_grain_size_override(grain_size)?;
+        }
+
+        if let Some((physical_sector_size, logical_sector_size)) = builder.device_sector_sizes {
+            context.save_device_sector_overrides(physical_sector_size, logical_sector_size)?;
+        }
+        // ----------------------------------------------------------------------------
+
         match (
             builder.enable_read_write,
             builder.assign_device,
@@ -226,23 +375,6 @@ pub fn build(self) -> Result<Fdisk<'a>, FdiskBuilderError> {
             }
         }
 
-        // ----------------------------------------------------------------------------
-        // Override the device's preferred values.
-        // These overrides must be set BEFORE any assign_device_* function is called.
-
-        if let Some((cylinders, heads, sectors)) = builder.device_geometry {
-            context.save_device_geometry_overrides(cylinders, heads, sectors)?;
-        }
-
-        if let Some(grain_size) = builder.device_grain_size {
-            context.save_device_grain_size_override(grain_size)?;
-        }
-
-        if let Some((physical_sector_size, logical_sector_size)) = builder.device_sector_sizes {
-            context.save_device_sector_overrides(physical_sector_size, logical_sector_size)?;
-        }
-        // ----------------------------------------------------------------------------
-
         match builder.device_addressing {
             // Default
             None => context.set_device_addressing(DeviceAddressing::Sector)?,
@@ -10,27 +10,45 @@
 use crate::fdisk::Fdisk;
 use crate::fdisk::FdiskError;
 
+use crate::core::partition_table::PartitionTableKind;
+
 /// `DOS` specific functions for a [`Fdisk`].
 ///
 /// This trait is sealed and can not be implemented for types outside of `rsfdisk`.
 pub trait FdiskDOSExt: Sealed {
     #[cfg(fdisk = "v2_39")]
-    /// Fixes the starting and ending LBA values for every partition according to their relative
+    /// Fixes the starting and ending CHS values for every partition according to their relative
     /// offset, size, and disk geometry (sectors per track and number of heads), then returns the
     /// number of modified partitions.
-    fn dos_fix_chs_values(&mut self) -> usize;
+    ///
+    /// Returns `0` if all CHS values were already consistent with the partitions' LBA values, or
+    /// an error if the assigned device's partition table is not a `DOS` partition table.
+    fn dos_fix_chs_values(&mut self) -> Result<usize, FdiskError>;
 
     /// Interactively relocates a `DOS` partition on disk.
+    ///
+    /// Returns an error if the assigned device's partition table is not a `DOS` partition table.
     fn dos_relocate_partition(&mut self, partition_number: usize) -> Result<(), FdiskError>;
 }
 
 impl<'a> FdiskDOSExt for Fdisk<'a> {
     #[cfg(fdisk = "v2_39")]
-    fn dos_fix_chs_values(&mut self) -> usize {
+    fn dos_fix_chs_values(&mut self) -> Result<usize, FdiskError> {
+        log::debug!("Fdisk::dos_fix_chs_values fixing DOS Cylinder/Head/Sector values");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::DOS) {
+            let err_msg =
+                "failed to fix Cylinder/Head/Sector values. No DOS partition table on device"
+                    .to_owned();
+            log::debug!("Fdisk::dos_fix_chs_values {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
         let modifications = unsafe { libfdisk::fdisk_dos_fix_chs(self.inner) as usize };
-        log::debug!("Fdisk::dos_fix_chs_values fixing DOS Cylinder/Head/Sector values, modified {:?} values", modifications);
+        log::debug!("Fdisk::dos_fix_chs_values fixed DOS Cylinder/Head/Sector values, modified {:?} partitions", modifications);
 
-        modifications
+        Ok(modifications)
     }
 
     fn dos_relocate_partition(&mut self, partition_number: usize) -> Result<(), FdiskError> {
@@ -39,6 +57,16 @@ fn dos_relocate_partition(&mut self, partition_number: usize) -> Result<(), Fdis
             partition_number
         );
 
+        if !self.partition_table_is_of_type(PartitionTableKind::DOS) {
+            let err_msg = format!(
+                "failed to relocate partition {:?}. No DOS partition table on device",
+                partition_number
+            );
+            log::debug!("Fdisk::dos_relocate_partition {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
         let result = unsafe { libfdisk::fdisk_dos_move_begin(self.inner, partition_number) };
 
         match result {
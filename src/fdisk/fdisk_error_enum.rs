@@ -16,6 +16,11 @@ pub enum FdiskError {
     #[error("{0}")]
     AssignDevice(String),
 
+    /// Error when a boot file path is rejected by a partition table (e.g. the path is too long
+    /// for the field reserved in an `SGI` volume header).
+    #[error("{0}")]
+    BootFile(String),
+
     /// Error while closing the device assigned to a [`Fdisk`](crate::fdisk::Fdisk) instance.
     #[error("{0}")]
     CloseDevice(String),
@@ -56,6 +61,10 @@ pub enum FdiskError {
     #[error("{0}")]
     NoNextPartitionNumber(String),
 
+    /// Error when no partition matches a given identification number.
+    #[error("{0}")]
+    NoSuchPartition(String),
+
     /// Error when trying to allocate memory.
     #[error("{0}")]
     OutOfMemory(String),
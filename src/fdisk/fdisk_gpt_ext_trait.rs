@@ -4,13 +4,22 @@
 // From dependency library
 
 // From standard library
-use std::mem::MaybeUninit;
+use std::fs::File;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::os::fd::FromRawFd;
+use std::os::unix::fs::FileExt;
 
 // From this library
+use crate::core::partition::Code;
+use crate::core::partition::GPTFlag;
+use crate::core::partition::Partition;
+use crate::core::partition::PartitionKind;
 use crate::core::private::Sealed;
 use crate::fdisk::Fdisk;
 use crate::fdisk::FdiskError;
 
+use crate::core::partition_table::PartitionTableKind;
+
 /// `GPT` specific functions for a [`Fdisk`].
 ///
 /// This trait is sealed and can not be implemented for types outside of `rsfdisk`.
@@ -37,15 +46,200 @@ pub trait FdiskGPTExt: Sealed {
     /// | Bits 48-63 |                      | Reserved for GUID specific use. The use of these bits will vary depending on the PartitionTypeGUID . Only the owner of the PartitionTypeGUID is allowed to modify these bits. They must be preserved if Bits 0-47 are modified.                                                                                                                                                                                                                                                                                                                                                                                                                                                                                      |
     fn gpt_attribute_bits(&self, partition_number: usize) -> Option<u64>;
 
-    /// Sets a partition's attribute bits.
-    fn gpt_set_attribute_bits<T>(
+    /// Sets a partition's attribute bits, replacing the current ones.
+    ///
+    /// Use [`GPTFlag::attribute_bit`](crate::core::partition::GPTFlag::attribute_bit) to get the
+    /// position of a named attribute in `attribute_bits`, e.g.
+    /// `1 << GPTFlag::Bit63.attribute_bit().unwrap()`.
+    ///
+    /// Returns an error if any of the bits 3-47, reserved by the UEFI specification, is set.
+    fn gpt_set_attribute_bits(
         &mut self,
         partition_number: usize,
         attribute_bits: u64,
     ) -> Result<(), FdiskError>;
 
+    /// Clears all of a partition's attribute bits, including the type-specific bits 48-63.
+    ///
+    /// Returns a [`FdiskError::Config`] error if the assigned device's partition table is not a
+    /// `GPT`, or a [`FdiskError::NoSuchPartition`] error if there is no partition with the given
+    /// identification number.
+    fn gpt_clear_attribute_bits(&mut self, partition_number: usize) -> Result<(), FdiskError>;
+
     /// Sets the maximum number of elements in the Partition Entry Array for a `GPT` partition table.
     fn gpt_set_partition_entry_array_size(&mut self, size: u32) -> Result<(), FdiskError>;
+
+    /// Returns the LBA of the `GPT` backup header, or `None` if the assigned device's partition
+    /// table is not a `GPT`.
+    ///
+    /// The backup header normally sits on the device's last LBA. However, if the device was
+    /// resized and the backup header relocation was disabled (see
+    /// [`PartitionTableGPTExt::gpt_disable_backup_header_relocation`](crate::core::partition_table::PartitionTableGPTExt::gpt_disable_backup_header_relocation)),
+    /// the backup header stays where the partition table's primary header says it is.
+    ///
+    /// **Note:** the value returned is read from the in-memory copy of the partition table.
+    fn gpt_backup_header_lba(&self) -> Result<Option<u64>, FdiskError>;
+
+    /// Returns the number of sectors taken up by the `GPT` partition table structures, or `None`
+    /// if the assigned device's partition table is not a `GPT`.
+    ///
+    /// The value covers the Protective MBR, the primary and backup Headers, and the primary and
+    /// backup Partition Entry Arrays. It depends on the number of entries in the Partition Entry
+    /// Array (see [`FdiskGPTExt::gpt_set_partition_entry_array_size`]), and on the device's
+    /// logical sector size. With the default 128 entries, it is `67` sectors on a device with
+    /// 512-byte sectors, and `11` sectors on a device with 4096-byte sectors.
+    fn gpt_overhead_sectors(&self) -> Result<Option<u64>, FdiskError>;
+
+    /// Returns the raw content of the `GPT` Partition Entry Array element describing the partition
+    /// with the given identification number, or `None` if the element is unused (i.e. its
+    /// partition type GUID is all zeros).
+    ///
+    /// Returns an error if the assigned device's partition table is not a `GPT`, or if
+    /// `partition_number` is out of the Partition Entry Array's bounds.
+    ///
+    /// **Note:** the bytes are read from the primary Partition Entry Array on the assigned device,
+    /// they do not reflect changes that have not yet been written to disk.
+    fn gpt_partition_entry_bytes(
+        &self,
+        partition_number: usize,
+    ) -> Result<Option<[u8; 128]>, FdiskError>;
+
+    /// Sets (`read_only` is `true`), or clears (`read_only` is `false`), the read-only attribute
+    /// (bit 60) of a partition.
+    ///
+    /// **Note:** the semantics of bits 48-63 depend on the partition's type; this method follows
+    /// the definitions given by Microsoft for basic data partitions.
+    fn gpt_set_partition_read_only(
+        &mut self,
+        partition_number: usize,
+        read_only: bool,
+    ) -> Result<(), FdiskError>;
+
+    /// Sets (`hidden` is `true`), or clears (`hidden` is `false`), the hidden attribute (bit 62)
+    /// of a partition.
+    ///
+    /// **Note:** the semantics of bits 48-63 depend on the partition's type; this method follows
+    /// the definitions given by Microsoft for basic data partitions.
+    fn gpt_set_partition_hidden(
+        &mut self,
+        partition_number: usize,
+        hidden: bool,
+    ) -> Result<(), FdiskError>;
+
+    /// Sets (`no_automount` is `true`), or clears (`no_automount` is `false`), the attribute
+    /// (bit 63) preventing a partition from being assigned a drive letter, i.e. automatically
+    /// mounted.
+    ///
+    /// **Note:** the semantics of bits 48-63 depend on the partition's type; this method follows
+    /// the definitions given by Microsoft for basic data partitions.
+    fn gpt_set_partition_no_automount(
+        &mut self,
+        partition_number: usize,
+        no_automount: bool,
+    ) -> Result<(), FdiskError>;
+
+    /// Returns the entries of a hybrid `MBR` (see [`FdiskGPTExt::gpt_is_hybrid`]), i.e. the `MBR`
+    /// partitions shadowing `GPT` partitions, or an empty `Vec` for a pure Protective MBR.
+    ///
+    /// Each [`Partition`] returned has its number set to the index of its `MBR` slot (`0` to
+    /// `3`), and its starting sector, size, and type code set to the values in the slot. The
+    /// protective entry (type `0xee`) and empty slots are not included.
+    ///
+    /// Returns an error if the assigned device's partition table is not a `GPT`.
+    ///
+    /// **Note:** the entries are read from the first sector of the assigned device, they do not
+    /// reflect changes that have not yet been written to disk.
+    fn gpt_hybrid_mbr_entries(&self) -> Result<Vec<Partition>, FdiskError>;
+}
+
+/// Size in bytes of an `MBR`.
+const MBR_SIZE: usize = 512;
+
+/// Offset in bytes of the Partition Entry Array in an `MBR`.
+const MBR_ENTRIES_OFFSET: usize = 446;
+
+/// Size in bytes of an element of an `MBR` Partition Entry Array.
+const MBR_ENTRY_SIZE: usize = 16;
+
+/// Size in bytes of an element of a `GPT` Partition Entry Array, as defined by the UEFI
+/// specification.
+const GPT_ENTRY_SIZE: usize = 128;
+
+/// Index of a `GPT`'s primary Partition Entry Array in [`Fdisk::partition_table_section`].
+const GPT_SECTION_ENTRIES: i32 = 2;
+
+/// Index of a `GPT`'s backup header in [`Fdisk::partition_table_section`].
+const GPT_SECTION_BACKUP_HEADER: i32 = 4;
+
+/// Sets, or clears, the attribute bit matching `flag` of a partition, leaving the others
+/// unchanged.
+fn set_attribute_bit(
+    fdisk: &mut Fdisk,
+    partition_number: usize,
+    flag: GPTFlag,
+    enable: bool,
+) -> Result<(), FdiskError> {
+    let op_str = if enable { "set" } else { "clear" };
+
+    let bit = flag.attribute_bit().ok_or_else(|| {
+        let err_msg = format!(
+            "failed to {} attribute {:?}. Not a single bit",
+            op_str, flag
+        );
+        log::debug!("FdiskGPTExt::set_attribute_bit {}", err_msg);
+
+        FdiskError::Config(err_msg)
+    })?;
+
+    if !fdisk.partition_table_is_of_type(PartitionTableKind::GPT) {
+        let err_msg = format!(
+            "failed to {} attribute bit {:?}. No GPT partition table on device",
+            op_str, bit
+        );
+        log::debug!("FdiskGPTExt::set_attribute_bit {}", err_msg);
+
+        return Err(FdiskError::Config(err_msg));
+    }
+
+    let attributes = fdisk.gpt_attribute_bits(partition_number).ok_or_else(|| {
+        let err_msg = format!(
+            "failed to {} attribute bit {:?}. No partition with number: {:?}",
+            op_str, bit, partition_number
+        );
+        log::debug!("FdiskGPTExt::set_attribute_bit {}", err_msg);
+
+        FdiskError::NoSuchPartition(err_msg)
+    })?;
+
+    let attributes = if enable {
+        attributes | (1u64 << bit)
+    } else {
+        attributes & !(1u64 << bit)
+    };
+
+    fdisk.gpt_set_attribute_bits(partition_number, attributes)?;
+
+    let done_str = if enable { "set" } else { "cleared" };
+    log::debug!(
+        "FdiskGPTExt::set_attribute_bit {} attribute bit {:?} of partition {:?}",
+        done_str,
+        bit,
+        partition_number
+    );
+
+    Ok(())
+}
+
+/// Fills `buffer` with data read from the file descriptor `fd`, starting at `offset`.
+fn read_exact_at(fd: libc::c_int, buffer: &mut [u8], offset: u64) -> std::io::Result<()> {
+    if fd < 0 {
+        return Err(std::io::Error::from_raw_os_error(libc::EBADF));
+    }
+
+    // Borrow the file descriptor owned by `libfdisk`, without closing it on drop.
+    let file = ManuallyDrop::new(unsafe { File::from_raw_fd(fd) });
+
+    file.read_exact_at(buffer, offset)
 }
 
 impl<'a> FdiskGPTExt for Fdisk<'a> {
@@ -91,7 +285,7 @@ fn gpt_attribute_bits(&self, partition_number: usize) -> Option<u64> {
         }
     }
 
-    fn gpt_set_attribute_bits<T>(
+    fn gpt_set_attribute_bits(
         &mut self,
         partition_number: usize,
         attribute_bits: u64,
@@ -104,13 +298,15 @@ fn gpt_set_attribute_bits<T>(
 
         // Check that bits 3 to 47, reserved for expansion by future versions of the UEFI
         // specification, are not set.
-        let mut mask = 1u64 << 4;
+        let mut mask = 1u64 << 3;
         for i in 3..=47 {
             if attribute_bits & mask != 0 {
                 let err_msg = format!(
                     "attribute bits 3-47, reserved for future use, must be 0. But, bit {} is set to 1",
                     i
                 );
+                log::debug!("Fdisk::gpt_set_attribute_bits {}", err_msg);
+
                 return Err(FdiskError::Config(err_msg));
             }
 
@@ -143,6 +339,35 @@ fn gpt_set_attribute_bits<T>(
         }
     }
 
+    fn gpt_clear_attribute_bits(&mut self, partition_number: usize) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::gpt_clear_attribute_bits clearing attribute bits of partition {:?}",
+            partition_number
+        );
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            let err_msg = format!(
+                "failed to clear attribute bits of partition {:?}. No GPT partition table on device",
+                partition_number
+            );
+            log::debug!("Fdisk::gpt_clear_attribute_bits {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        if self.gpt_attribute_bits(partition_number).is_none() {
+            let err_msg = format!(
+                "failed to clear attribute bits. No partition with number: {:?}",
+                partition_number
+            );
+            log::debug!("Fdisk::gpt_clear_attribute_bits {}", err_msg);
+
+            return Err(FdiskError::NoSuchPartition(err_msg));
+        }
+
+        self.gpt_set_attribute_bits(partition_number, 0)
+    }
+
     fn gpt_set_partition_entry_array_size(&mut self, size: u32) -> Result<(), FdiskError> {
         log::debug!("Fdisk::gpt_set_partition_entry_array_size setting GPT partition entry array size to: {:?}", size);
 
@@ -165,4 +390,266 @@ fn gpt_set_partition_entry_array_size(&mut self, size: u32) -> Result<(), FdiskE
             }
         }
     }
+
+    fn gpt_backup_header_lba(&self) -> Result<Option<u64>, FdiskError> {
+        log::debug!("Fdisk::gpt_backup_header_lba locating GPT backup header");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            log::debug!("Fdisk::gpt_backup_header_lba no GPT partition table on device");
+
+            return Ok(None);
+        }
+
+        match self.partition_table_section(GPT_SECTION_BACKUP_HEADER) {
+            Some(section) => {
+                let lba = section.starting_offset() / self.device_bytes_per_logical_sector();
+                log::debug!("Fdisk::gpt_backup_header_lba value: {:?}", lba);
+
+                Ok(Some(lba))
+            }
+            None => {
+                let err_msg = "failed to locate GPT backup header".to_owned();
+                log::debug!("Fdisk::gpt_backup_header_lba {}", err_msg);
+
+                Err(FdiskError::Config(err_msg))
+            }
+        }
+    }
+
+    fn gpt_overhead_sectors(&self) -> Result<Option<u64>, FdiskError> {
+        log::debug!("Fdisk::gpt_overhead_sectors computing size of GPT structures");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            log::debug!("Fdisk::gpt_overhead_sectors no GPT partition table on device");
+
+            return Ok(None);
+        }
+
+        let sector_size = self.device_bytes_per_logical_sector();
+        let mut sections = 0;
+        let mut sectors = 0;
+        while let Some(section) = self.partition_table_section(sections) {
+            // Each structure starts on a sector boundary.
+            sectors += (section.size() as u64).div_ceil(sector_size);
+            sections += 1;
+        }
+
+        if sections == 0 {
+            let err_msg = "failed to locate GPT partition table sections".to_owned();
+            log::debug!("Fdisk::gpt_overhead_sectors {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        log::debug!(
+            "Fdisk::gpt_overhead_sectors GPT structures take up {:?} sectors",
+            sectors
+        );
+
+        Ok(Some(sectors))
+    }
+
+    fn gpt_partition_entry_bytes(
+        &self,
+        partition_number: usize,
+    ) -> Result<Option<[u8; 128]>, FdiskError> {
+        log::debug!(
+            "Fdisk::gpt_partition_entry_bytes reading GPT entry of partition {:?}",
+            partition_number
+        );
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            let err_msg = "no GPT partition table on device".to_owned();
+            log::debug!("Fdisk::gpt_partition_entry_bytes {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let max_entries = self.partition_table_max_partitions();
+        if partition_number >= max_entries {
+            let err_msg = format!(
+                "partition number {:?} out of GPT Partition Entry Array bounds (0-{:?})",
+                partition_number,
+                max_entries.saturating_sub(1)
+            );
+            log::debug!("Fdisk::gpt_partition_entry_bytes {}", err_msg);
+
+            return Err(FdiskError::NoSuchPartition(err_msg));
+        }
+
+        let section = self
+            .partition_table_section(GPT_SECTION_ENTRIES)
+            .ok_or_else(|| {
+                let err_msg = "failed to locate GPT Partition Entry Array".to_owned();
+                log::debug!("Fdisk::gpt_partition_entry_bytes {}", err_msg);
+
+                FdiskError::Config(err_msg)
+            })?;
+
+        let entry_size = (section.size() / max_entries) as u64;
+        if entry_size < GPT_ENTRY_SIZE as u64 {
+            let err_msg = format!("unexpected GPT Partition Entry size: {:?}", entry_size);
+            log::debug!("Fdisk::gpt_partition_entry_bytes {}", err_msg);
+
+            return Err(FdiskError::Unexpected(err_msg));
+        }
+
+        let offset = section.starting_offset() + partition_number as u64 * entry_size;
+        let fd = unsafe { libfdisk::fdisk_get_devfd(self.inner) };
+        let mut entry = [0u8; GPT_ENTRY_SIZE];
+
+        read_exact_at(fd, &mut entry, offset).map_err(|e| {
+            let err_msg = format!(
+                "failed to read GPT entry of partition {:?} {}",
+                partition_number, e
+            );
+            log::debug!("Fdisk::gpt_partition_entry_bytes {}", err_msg);
+
+            FdiskError::IoError(err_msg)
+        })?;
+
+        // The first 16 bytes of an entry hold its partition type GUID.
+        if entry[..16].iter().all(|&byte| byte == 0) {
+            log::debug!(
+                "Fdisk::gpt_partition_entry_bytes GPT entry of partition {:?} is unused",
+                partition_number
+            );
+
+            Ok(None)
+        } else {
+            log::debug!(
+                "Fdisk::gpt_partition_entry_bytes read GPT entry of partition {:?}",
+                partition_number
+            );
+
+            Ok(Some(entry))
+        }
+    }
+
+    fn gpt_hybrid_mbr_entries(&self) -> Result<Vec<Partition>, FdiskError> {
+        log::debug!("Fdisk::gpt_hybrid_mbr_entries reading hybrid MBR entries");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            let err_msg = "no GPT partition table on device".to_owned();
+            log::debug!("Fdisk::gpt_hybrid_mbr_entries {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let fd = unsafe { libfdisk::fdisk_get_devfd(self.inner) };
+        let mut mbr = [0u8; MBR_SIZE];
+
+        read_exact_at(fd, &mut mbr, 0).map_err(|e| {
+            let err_msg = format!("failed to read MBR {}", e);
+            log::debug!("Fdisk::gpt_hybrid_mbr_entries {}", err_msg);
+
+            FdiskError::IoError(err_msg)
+        })?;
+
+        if mbr[MBR_SIZE - 2..] != [0x55, 0xaa] {
+            let err_msg = "missing MBR boot signature".to_owned();
+            log::debug!("Fdisk::gpt_hybrid_mbr_entries {}", err_msg);
+
+            return Err(FdiskError::Unexpected(err_msg));
+        }
+
+        let mut entries = Vec::new();
+
+        for (slot, entry) in mbr[MBR_ENTRIES_OFFSET..MBR_ENTRIES_OFFSET + 4 * MBR_ENTRY_SIZE]
+            .chunks_exact(MBR_ENTRY_SIZE)
+            .enumerate()
+        {
+            let code = entry[4];
+            if code == 0x00 || code == u8::from(Code::GPTProtectiveMBR) {
+                continue;
+            }
+
+            let start = u32::from_le_bytes([entry[8], entry[9], entry[10], entry[11]]);
+            let size = u32::from_le_bytes([entry[12], entry[13], entry[14], entry[15]]);
+
+            let mut partition = Partition::builder()
+                .number(slot)
+                .starting_sector(start as u64)
+                .size_in_sectors(size as u64)
+                .build()
+                .map_err(|e| {
+                    let err_msg =
+                        format!("failed to create partition for MBR slot {:?} {}", slot, e);
+                    log::debug!("Fdisk::gpt_hybrid_mbr_entries {}", err_msg);
+
+                    FdiskError::Creation(err_msg)
+                })?;
+
+            // Leave the type unset for codes unknown to `rsfdisk`.
+            if let Ok(code) = Code::try_from(code) {
+                PartitionKind::builder()
+                    .code(code)
+                    .build()
+                    .map_err(|e| e.to_string())
+                    .and_then(|kind| {
+                        partition
+                            .set_partition_type(kind)
+                            .map_err(|e| e.to_string())
+                    })
+                    .map_err(|e| {
+                        let err_msg =
+                            format!("failed to set partition type of MBR slot {:?} {}", slot, e);
+                        log::debug!("Fdisk::gpt_hybrid_mbr_entries {}", err_msg);
+
+                        FdiskError::Creation(err_msg)
+                    })?;
+            }
+
+            entries.push(partition);
+        }
+
+        log::debug!(
+            "Fdisk::gpt_hybrid_mbr_entries found {:?} hybrid MBR entries",
+            entries.len()
+        );
+
+        Ok(entries)
+    }
+
+    fn gpt_set_partition_read_only(
+        &mut self,
+        partition_number: usize,
+        read_only: bool,
+    ) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::gpt_set_partition_read_only setting read-only attribute of partition {:?} to {:?}",
+            partition_number,
+            read_only
+        );
+
+        set_attribute_bit(self, partition_number, GPTFlag::Bit60, read_only)
+    }
+
+    fn gpt_set_partition_hidden(
+        &mut self,
+        partition_number: usize,
+        hidden: bool,
+    ) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::gpt_set_partition_hidden setting hidden attribute of partition {:?} to {:?}",
+            partition_number,
+            hidden
+        );
+
+        set_attribute_bit(self, partition_number, GPTFlag::Bit62, hidden)
+    }
+
+    fn gpt_set_partition_no_automount(
+        &mut self,
+        partition_number: usize,
+        no_automount: bool,
+    ) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::gpt_set_partition_no_automount setting no-automount attribute of partition {:?} to {:?}",
+            partition_number,
+            no_automount
+        );
+
+        set_attribute_bit(self, partition_number, GPTFlag::Bit63, no_automount)
+    }
 }
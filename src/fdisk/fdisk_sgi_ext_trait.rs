@@ -4,12 +4,22 @@
 // From dependency library
 
 // From standard library
+use std::path::Path;
 
 // From this library
 use crate::core::private::Sealed;
 use crate::fdisk::Fdisk;
 use crate::fdisk::FdiskError;
 
+use crate::core::partition_table::HeaderEntry;
+use crate::core::partition_table::PartitionTableKind;
+
+use crate::core::prompt::Prompt;
+use crate::core::prompt::PromptKind;
+
+/// Size in bytes of the boot file name field in a `SGI` volume header.
+const SGI_BOOT_FILE_MAX_LEN: usize = 16;
+
 /// `SGI` specific functions for a [`Fdisk`].
 ///
 /// This trait is sealed and can not be implemented for types outside of `rsfdisk`.
@@ -20,6 +30,26 @@ pub trait FdiskSGIExt: Sealed {
 
     /// Sets the SGI boot file by [`Prompt`](crate::core::prompt::Prompt)ing the user for a value.
     fn sgi_set_boot_file(&mut self) -> Result<(), FdiskError>;
+
+    /// Sets the SGI boot file to `path`, without prompting the user.
+    ///
+    /// Returns a [`FdiskError::BootFile`] error if `path` is not an absolute path, or if it is
+    /// longer than the 16 bytes reserved for it in the `SGI` volume header. Returns a
+    /// [`FdiskError::Config`] error if the assigned device's partition table is not a `SGI`
+    /// partition table.
+    ///
+    /// **Note:** this method temporarily installs its own prompt callback to provide the boot
+    /// file name to `libfdisk`, and restores the one set by
+    /// [`Fdisk::set_prompt_callback`](crate::fdisk::Fdisk::set_prompt_callback), if any, before
+    /// returning.
+    fn sgi_set_boot_file_path<T>(&mut self, path: T) -> Result<(), FdiskError>
+    where
+        T: AsRef<Path>;
+
+    /// Returns the path to the SGI boot file, or `None` if it is not set.
+    ///
+    /// Returns an error if the assigned device's partition table is not a `SGI` partition table.
+    fn sgi_boot_file(&self) -> Result<Option<String>, FdiskError>;
 }
 
 impl<'a> FdiskSGIExt for Fdisk<'a> {
@@ -62,4 +92,137 @@ fn sgi_set_boot_file(&mut self) -> Result<(), FdiskError> {
             }
         }
     }
+
+    fn sgi_set_boot_file_path<T>(&mut self, path: T) -> Result<(), FdiskError>
+    where
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        log::debug!(
+            "Fdisk::sgi_set_boot_file_path setting SGI boot file to: {:?}",
+            path
+        );
+
+        if !self.partition_table_is_of_type(PartitionTableKind::SGI) {
+            let err_msg =
+                "failed to set SGI boot file. No SGI partition table on device".to_owned();
+            log::debug!("Fdisk::sgi_set_boot_file_path {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let boot_file = path.to_str().ok_or_else(|| {
+            let err_msg = format!("boot file path {:?} is not valid UTF-8", path);
+            log::debug!("Fdisk::sgi_set_boot_file_path {}", err_msg);
+
+            FdiskError::BootFile(err_msg)
+        })?;
+
+        if !path.is_absolute() || boot_file.len() < 2 {
+            let err_msg = format!(
+                "boot file path {:?} must be an absolute non-root path (e.g. \"/unix\")",
+                path
+            );
+            log::debug!("Fdisk::sgi_set_boot_file_path {}", err_msg);
+
+            return Err(FdiskError::BootFile(err_msg));
+        }
+
+        if boot_file.len() > SGI_BOOT_FILE_MAX_LEN {
+            let err_msg = format!(
+                "boot file path {:?} is too long: {} bytes maximum, got {}",
+                path,
+                SGI_BOOT_FILE_MAX_LEN,
+                boot_file.len()
+            );
+            log::debug!("Fdisk::sgi_set_boot_file_path {}", err_msg);
+
+            return Err(FdiskError::BootFile(err_msg));
+        }
+
+        unsafe extern "C" fn answer_boot_file(
+            _: *mut libfdisk::fdisk_context,
+            mut ask: *mut libfdisk::fdisk_ask,
+            data: *mut libc::c_void,
+        ) -> libc::c_int {
+            // `Prompt` is a transparent wrapper around a `libfdisk::fdisk_ask` pointer.
+            let prompt = &mut *(&mut ask as *mut _ as *mut Prompt);
+            let boot_file = &*(data as *const &str);
+
+            // Unwinding across an `extern "C"` boundary aborts the process, catch panics here.
+            let result =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| match prompt.kind() {
+                    PromptKind::String => match prompt.string_set_answer(boot_file) {
+                        Ok(_) => 0,
+                        Err(_) => -libc::EINVAL,
+                    },
+                    PromptKind::Info | PromptKind::Warn | PromptKind::WarnX => 0,
+                    _ => -libc::EINVAL,
+                }));
+
+            match result {
+                Ok(code) => code,
+                Err(_) => {
+                    log::debug!("Fdisk::sgi_set_boot_file_path boot file prompt callback panicked");
+
+                    -libc::EINVAL
+                }
+            }
+        }
+
+        unsafe {
+            libfdisk::fdisk_set_ask(
+                self.inner,
+                Some(answer_boot_file),
+                &boot_file as *const _ as *mut libc::c_void,
+            );
+        }
+
+        let result = unsafe { libfdisk::fdisk_sgi_set_bootfile(self.inner) };
+
+        Fdisk::restore_prompt_callback(self);
+
+        match result {
+            0 => {
+                log::debug!(
+                    "Fdisk::sgi_set_boot_file_path set SGI boot file to: {:?}",
+                    boot_file
+                );
+
+                Ok(())
+            }
+            code => {
+                let err_msg = format!("failed to set SGI boot file to: {:?}", boot_file);
+                log::debug!("Fdisk::sgi_set_boot_file_path {}. libfdisk::fdisk_sgi_set_bootfile returned error code: {:?}", err_msg, code);
+
+                Err(FdiskError::Config(err_msg))
+            }
+        }
+    }
+
+    fn sgi_boot_file(&self) -> Result<Option<String>, FdiskError> {
+        log::debug!("Fdisk::sgi_boot_file getting SGI boot file");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::SGI) {
+            let err_msg =
+                "failed to get SGI boot file. No SGI partition table on device".to_owned();
+            log::debug!("Fdisk::sgi_boot_file {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let content = self
+            .partition_table_header_entry(HeaderEntry::SgiBootfile)
+            .ok_or_else(|| {
+                let err_msg = "failed to get SGI boot file".to_owned();
+                log::debug!("Fdisk::sgi_boot_file {}", err_msg);
+
+                FdiskError::Config(err_msg)
+            })?;
+
+        let boot_file = content.data_string().map(str::to_owned);
+        log::debug!("Fdisk::sgi_boot_file value: {:?}", boot_file);
+
+        Ok(boot_file)
+    }
 }
@@ -4,51 +4,76 @@
 // From dependency library
 
 // From standard library
+use std::collections::BTreeMap;
 use std::ffi::CString;
 use std::fs::File;
 use std::mem::MaybeUninit;
 use std::os::fd::{BorrowedFd, IntoRawFd};
 use std::path::Path;
+use std::path::PathBuf;
 use std::slice;
 
 // From this library
+use crate::core::errors::ParserError;
+
+use crate::fdisk::AlignmentTarget;
 use crate::fdisk::CtxBuilder;
 use crate::fdisk::DeviceAddressing;
 use crate::fdisk::FdiskBuilder;
 use crate::fdisk::FdiskError;
+use crate::fdisk::FdiskGPTExt;
 use crate::fdisk::GcItem;
 use crate::fdisk::LBAAlign;
+use crate::fdisk::PartitionLayout;
 use crate::fdisk::PartitionTableIter;
 use crate::fdisk::PartitionTableIterMut;
 use crate::fdisk::SizeFormat;
+use crate::fdisk::WriteStage;
 
 use crate::core::partition::BitFlag;
+use crate::core::partition::Code;
+use crate::core::partition::Guid;
+use crate::core::partition::LayoutProblem;
 use crate::core::partition::Partition;
 use crate::core::partition::PartitionKind;
 use crate::core::partition::PartitionList;
+use crate::core::partition::PartitionSpec;
 
+use crate::core::partition_table::DiskLayout;
 use crate::core::partition_table::Field;
 use crate::core::partition_table::FieldFormat;
 use crate::core::partition_table::HeaderEntry;
 use crate::core::partition_table::HeaderEntryContent;
 use crate::core::partition_table::PartitionTable;
+use crate::core::partition_table::PartitionTableDOSExt;
 use crate::core::partition_table::PartitionTableKind;
+use crate::core::partition_table::PartitionTableSummary;
 use crate::core::partition_table::TableSection;
+use crate::core::partition_table::VerificationReport;
 use crate::core::partition_table::VerificationStatus;
 
+use crate::core::prompt::Prompt;
+use crate::core::prompt::PromptKind;
+
 use crate::core::script::Script;
 
+use crate::core::utils::misc;
+
 use crate::ffi_to_string_or_empty;
 use crate::ffi_utils;
 use crate::owning_mut_from_ptr;
 use crate::owning_ref_from_ptr;
 
+/// Function answering the [`Prompt`]s emitted by `libfdisk`.
+type PromptCallback = Box<dyn FnMut(&mut Prompt) -> Result<(), FdiskError>>;
+
 /// Partition table reader/editor/creator.
 #[derive(Debug)]
 pub struct Fdisk<'a> {
     pub(crate) inner: *mut libfdisk::fdisk_context,
     _parent: Option<&'a Fdisk<'a>>,
     pub(crate) gc: Vec<GcItem>,
+    prompt_callback: Option<*mut PromptCallback>,
 }
 
 impl<'a> Fdisk<'a> {
@@ -63,6 +88,7 @@ pub(crate) fn from_ptr(
             inner: ptr,
             _parent: parent,
             gc: vec![],
+            prompt_callback: None,
         }
     }
 
@@ -144,6 +170,31 @@ fn make_new_nested_partitioner(
     /// The returned `Fdisk` is initialized with data taken from its parent, sharing settings and
     /// the assigned device; changes to the nested context are propagated to its parent, but not the
     /// other way around.
+    ///
+    /// # Lifetime
+    ///
+    /// A nested `Fdisk` mutably borrows its parent for as long as it exists: the parent can
+    /// neither be used nor dropped until the nested `Fdisk` is dropped. This guarantees that the
+    /// references the nested `Fdisk` hands out (e.g. with [`Fdisk::parent_partitioner`]), and the
+    /// prompt callback it inherits from its parent (see [`Fdisk::set_prompt_callback`]), never
+    /// dangle.
+    ///
+    /// The following does not compile, since `nested` would outlive `disk`:
+    ///
+    /// ```compile_fail,E0597
+    /// use rsfdisk::fdisk::Fdisk;
+    ///
+    /// fn main() -> rsfdisk::Result<()> {
+    ///     let nested = {
+    ///         let mut disk = Fdisk::builder().assign_device("/dev/vda").build()?;
+    ///         disk.create_nested_partitioner()?
+    ///     };
+    ///
+    ///     drop(nested);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn create_nested_partitioner(&'a mut self) -> Result<Fdisk, FdiskError> {
         Self::make_new_nested_partitioner(self, "")
     }
@@ -161,6 +212,56 @@ pub fn create_nested_partitioner_with_name<T>(
         Self::make_new_nested_partitioner(self, name)
     }
 
+    /// Returns a new nested `Fdisk`, acts the same as [`Fdisk::create_nested_partitioner`],
+    /// and initializes a nested partition table of the given `kind`. If no such partition table
+    /// exists yet on the device, one is created in memory.
+    ///
+    /// Supported combinations are:
+    /// - a `BSD` partition table nested in a `DOS` partition table (i.e. a `BSD disklabel` in a
+    ///   `MBR` partition),
+    /// - a `DOS` partition table nested in a `GPT` partition table (i.e. a protective or hybrid
+    ///   `MBR`).
+    ///
+    /// Returns an error for any other combination.
+    pub fn create_nested_partitioner_for(
+        &'a mut self,
+        kind: PartitionTableKind,
+    ) -> Result<Fdisk, FdiskError> {
+        log::debug!(
+            "Fdisk::create_nested_partitioner_for creating a nested `Fdisk` for partition table: {:?}",
+            kind
+        );
+
+        let parent_kind = match kind {
+            PartitionTableKind::BSD => PartitionTableKind::DOS,
+            PartitionTableKind::DOS => PartitionTableKind::GPT,
+            _ => {
+                let err_msg = format!("unsupported nested partition table: {:?}", kind);
+                log::debug!("Fdisk::create_nested_partitioner_for {}", err_msg);
+
+                return Err(FdiskError::Creation(err_msg));
+            }
+        };
+
+        if !self.partition_table_is_of_type(parent_kind) {
+            let err_msg = format!(
+                "can not nest a {:?} partition table outside of a {:?} partition table",
+                kind, parent_kind
+            );
+            log::debug!("Fdisk::create_nested_partitioner_for {}", err_msg);
+
+            return Err(FdiskError::Creation(err_msg));
+        }
+
+        let mut nested = Self::make_new_nested_partitioner(self, kind.as_str())?;
+
+        if !nested.partition_table_is_of_type(kind) {
+            nested.partition_table_create(kind)?;
+        }
+
+        Ok(nested)
+    }
+
     //---- BEGIN setters
 
     #[doc(hidden)]
@@ -830,6 +931,11 @@ pub fn discard_changes(&mut self) -> Result<(), FdiskError> {
     }
 
     /// Forces the kernel to reread metadata about partitions in the partition table on the assigned device.
+    ///
+    /// **Note:** the kernel refuses to reread the whole partition table (`BLKRRPART` fails with
+    /// `EBUSY`) while any partition on the device is in use. In that case, use
+    /// [`Fdisk::reread_changed_partition_entries`] which updates the kernel's view one partition
+    /// at a time.
     pub fn reread_partition_entries(&mut self) -> Result<(), FdiskError> {
         log::debug!("Fdisk::reread_partition_entries rereading partitions in partition table");
 
@@ -850,11 +956,24 @@ pub fn reread_partition_entries(&mut self) -> Result<(), FdiskError> {
         }
     }
 
-    /// Restores changed in-memory partition entries in the partition table to the same state as the one in the
-    /// `entries_on_disk` parameter.
+    /// Informs the kernel only about the partitions that differ between `entries_on_disk` and the
+    /// current partition table.
+    ///
+    /// `entries_on_disk` is the list of partitions as they were before modification, usually
+    /// captured with [`Fdisk::used_partitions`] right after assigning the device. Call this method
+    /// after writing the new partition table (see [`Fdisk::partition_table_write_to_disk`]).
+    ///
+    /// Unlike [`Fdisk::reread_partition_entries`], which asks the kernel to reread the whole
+    /// partition table (`BLKRRPART`) and fails if any partition on the device is in use, this
+    /// method adds, resizes, or deletes each changed partition individually (`BLKPG`). Therefore,
+    /// unmodified partitions can stay mounted while this method operates.
     ///
-    /// **Note:** this function does not force the kernel to reread the whole partition table.
-    /// Therefore, unmodified partitions can be mounted while this method operates.
+    /// Returns a [`FdiskError::Restore`] error if the kernel rejects any of the updates (e.g. a
+    /// changed partition is mounted). While this method operates, warnings issued by `libfdisk`
+    /// are not forwarded to the function set by [`Fdisk::set_prompt_callback`].
+    ///
+    /// **Note:** the kernel does not track partitions in image files, this method does nothing
+    /// and returns `Ok(())` when the assigned device is an image file.
     pub fn reread_changed_partition_entries(
         &mut self,
         entries_on_disk: &PartitionList,
@@ -863,20 +982,94 @@ pub fn reread_changed_partition_entries(
             "Fdisk::reread_changed_partition_entries rereading changed partition table entries"
         );
 
-        let result = unsafe { libfdisk::fdisk_reread_changes(self.inner, entries_on_disk.inner) };
+        if self.device_is_image_file() {
+            log::debug!("Fdisk::reread_changed_partition_entries assigned device is an image file, nothing to do");
+
+            return Ok(());
+        }
+
+        Self::reread_changes(self, entries_on_disk).map_err(|code| {
+            let err_msg = "failed to reread changed partition table entries".to_owned();
+            log::debug!("Fdisk::reread_changed_partition_entries {}. libfdisk::fdisk_reread_changes failed with error code: {:?}", err_msg, code);
+
+            FdiskError::Restore(err_msg)
+        })
+    }
+
+    #[doc(hidden)]
+    /// Informs the kernel about the partitions that differ between `entries_on_disk` and the
+    /// current partition table. Returns a negative error code on failure.
+    ///
+    /// When the kernel rejects a partition update, `libfdisk` only issues a warning and still
+    /// returns `0`. This function captures those warnings with a temporary prompt callback, and
+    /// returns `-errno` of the first rejected update (or `-EBUSY` if every update failed
+    /// because the device is busy).
+    fn reread_changes(ptr: &Self, entries_on_disk: &PartitionList) -> Result<(), i32> {
+        unsafe extern "C" fn collect_warnings(
+            _: *mut libfdisk::fdisk_context,
+            mut ask: *mut libfdisk::fdisk_ask,
+            data: *mut libc::c_void,
+        ) -> libc::c_int {
+            // `Prompt` is a transparent wrapper around a `libfdisk::fdisk_ask` pointer.
+            let prompt = &mut *(&mut ask as *mut _ as *mut Prompt);
+            let errors = &mut *(data as *mut Vec<i32>);
+
+            // Unwinding across an `extern "C"` boundary aborts the process, catch panics here.
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                if prompt.is_of_kind(PromptKind::Warn) {
+                    log::debug!(
+                        "Fdisk::reread_changes kernel rejected a partition update: {:?}",
+                        prompt.error_message()
+                    );
+
+                    match prompt.error_number() {
+                        errno if errno > 0 => errors.push(errno),
+                        _ => errors.push(libc::EIO),
+                    }
+                }
+            }));
+
+            if result.is_err() {
+                log::debug!("Fdisk::reread_changes warning collector panicked");
+            }
+
+            0
+        }
+
+        let mut errors: Vec<i32> = Vec::new();
+
+        unsafe {
+            libfdisk::fdisk_set_ask(
+                ptr.inner,
+                Some(collect_warnings),
+                &mut errors as *mut _ as *mut libc::c_void,
+            );
+        }
+
+        let result = unsafe { libfdisk::fdisk_reread_changes(ptr.inner, entries_on_disk.inner) };
+
+        Self::restore_prompt_callback(ptr);
 
         match result {
-            0 => {
-                log::debug!("Fdisk::reread_changed_partition_entries reread changed partition table entries");
+            0 if errors.is_empty() => {
+                log::debug!("Fdisk::reread_changes reread changed partition table entries");
 
                 Ok(())
             }
-            code => {
-                let err_msg = "failed to reread changed partition table entries".to_owned();
-                log::debug!("Fdisk::reread_changed_partition_entries {}. libfdisk::fdisk_reread_changes returned error code: {:?}", err_msg, code);
+            0 => {
+                let errno = errors
+                    .iter()
+                    .copied()
+                    .find(|&errno| errno != libc::EBUSY)
+                    .unwrap_or(libc::EBUSY);
+                log::debug!(
+                    "Fdisk::reread_changes kernel rejected {:?} partition update(s)",
+                    errors.len()
+                );
 
-                Err(FdiskError::Restore(err_msg))
+                Err(-errno)
             }
+            code => Err(code),
         }
     }
 
@@ -954,6 +1147,72 @@ pub fn device_set_last_lba(&mut self, address: u64) -> Result<(), FdiskError> {
         }
     }
 
+    /// Reserves `sectors` logical sectors at the end of the assigned device, by moving the last
+    /// usable logical block address (see [`Fdisk::device_set_last_lba`]) to
+    /// `device_size_in_sectors - sectors - 1`. Partitions created without an explicit end will
+    /// stop short of the reserved region.
+    ///
+    /// Returns an error if the reservation would leave no usable sector, or if it would overlap
+    /// an existing partition.
+    ///
+    /// **Note:** the reservation is counted from the last sector of the device, not from the end
+    /// of the usable area. On a `GPT` partition table, it therefore includes the sectors the
+    /// partition table already keeps for its backup Header and Partition Entry Array (`33`
+    /// sectors with 512-byte sectors and the default 128 entries). `libfdisk` also bounds `GPT`
+    /// partitions by the `Last Usable LBA` recorded in the Header, so the effective limit is the
+    /// lower of the two; reserving fewer sectors than the table's own backup area has no effect.
+    ///
+    /// **Note:** like [`Fdisk::device_set_last_lba`], the reservation is lost after calling
+    /// [`Fdisk::override_device_geometry`] or [`Fdisk::restore_default_lba_alignment`].
+    pub fn set_reserved_tail_sectors(&mut self, sectors: u64) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::set_reserved_tail_sectors reserving {:?} sectors at the end of the device",
+            sectors
+        );
+
+        let total_sectors = self.device_size_in_sectors();
+        let first_lba = self.device_first_lba();
+
+        let last_lba = match total_sectors.checked_sub(sectors.saturating_add(1)) {
+            Some(last_lba) if last_lba > first_lba => last_lba,
+            _ => {
+                let err_msg = format!(
+                    "can not reserve {:?} sectors on a device of {:?} sectors with first usable LBA {:?}",
+                    sectors, total_sectors, first_lba
+                );
+                log::debug!("Fdisk::set_reserved_tail_sectors {}", err_msg);
+
+                return Err(FdiskError::ResultOutOfRange(err_msg));
+            }
+        };
+
+        if let Some(partitions) = self.list_partitions() {
+            let overlapping = partitions
+                .iter()
+                .filter(|partition| partition.points_to_used_area())
+                .find(|partition| {
+                    partition
+                        .ending_sector()
+                        .map(|end| end > last_lba)
+                        .unwrap_or(false)
+                });
+
+            if let Some(partition) = overlapping {
+                let err_msg = format!(
+                    "reserved area starting at sector {:?} overlaps partition {:?} ending at sector {:?}",
+                    last_lba + 1,
+                    partition.number(),
+                    partition.ending_sector()
+                );
+                log::debug!("Fdisk::set_reserved_tail_sectors {}", err_msg);
+
+                return Err(FdiskError::ResultOutOfRange(err_msg));
+            }
+        }
+
+        self.device_set_last_lba(last_lba)
+    }
+
     #[doc(hidden)]
     /// Align the LBA address to multiple of the device grain size.
     fn align_lba(fdisk: &mut Self, address: u64, direction: LBAAlign) -> Result<(), FdiskError> {
@@ -1030,6 +1289,70 @@ pub fn align_lba_nearest(&mut self, address: u64) -> Result<(), FdiskError> {
         Self::align_lba(self, address, LBAAlign::Nearest)
     }
 
+    #[doc(hidden)]
+    /// Returns the LBA address aligned in the given direction.
+    fn aligned_lba(fdisk: &Self, address: u64, direction: LBAAlign) -> u64 {
+        let aligned = unsafe { libfdisk::fdisk_align_lba(fdisk.inner, address, direction.into()) };
+        log::debug!(
+            "Fdisk::aligned_lba address: {:?} aligned {} to: {:?}",
+            address,
+            direction,
+            aligned
+        );
+
+        aligned
+    }
+
+    #[doc(hidden)]
+    /// Brings an aligned LBA address back within the range of usable addresses.
+    fn clamp_aligned_lba(fdisk: &Self, aligned: u64) -> u64 {
+        let first_lba = fdisk.device_first_lba();
+        let last_lba = fdisk.device_last_lba();
+        let in_range = |lba: &u64| (first_lba..=last_lba).contains(lba);
+
+        if aligned > last_lba {
+            Some(Self::aligned_lba(fdisk, last_lba, LBAAlign::Down))
+                .filter(in_range)
+                .unwrap_or(last_lba)
+        } else if aligned < first_lba {
+            Some(Self::aligned_lba(fdisk, first_lba, LBAAlign::Up))
+                .filter(in_range)
+                .unwrap_or(first_lba)
+        } else {
+            aligned
+        }
+    }
+
+    /// Returns the LBA aligned to the next block/sector boundary, clamped to the range of usable
+    /// addresses on the assigned device (see [`Fdisk::device_first_lba`] and
+    /// [`Fdisk::device_last_lba`]).
+    ///
+    /// If the aligned LBA falls outside the usable range, this method returns the aligned LBA
+    /// closest to the range's boundary instead, or the boundary itself if there is no aligned
+    /// address within the range.
+    pub fn align_lba_up_bounded(&self, lba: u64) -> u64 {
+        let aligned = Self::aligned_lba(self, lba, LBAAlign::Up);
+        let address = Self::clamp_aligned_lba(self, aligned);
+        log::debug!("Fdisk::align_lba_up_bounded address: {:?}", address);
+
+        address
+    }
+
+    /// Returns the LBA aligned to the previous block/sector boundary, clamped to the range of
+    /// usable addresses on the assigned device (see [`Fdisk::device_first_lba`] and
+    /// [`Fdisk::device_last_lba`]).
+    ///
+    /// If the aligned LBA falls outside the usable range, this method returns the aligned LBA
+    /// closest to the range's boundary instead, or the boundary itself if there is no aligned
+    /// address within the range.
+    pub fn align_lba_down_bounded(&self, lba: u64) -> u64 {
+        let aligned = Self::aligned_lba(self, lba, LBAAlign::Down);
+        let address = Self::clamp_aligned_lba(self, aligned);
+        log::debug!("Fdisk::align_lba_down_bounded address: {:?}", address);
+
+        address
+    }
+
     /// Returns the value of the aligned LBA address in the given sector range.
     pub fn align_lba_in_range(&mut self, lba: u64, lower_bound: u64, upper_bound: u64) -> u64 {
         let address = unsafe {
@@ -1111,6 +1434,118 @@ pub fn restore_device_properties(&mut self) -> Result<(), FdiskError> {
         }
     }
 
+    /// Overwrites with zeros `count` sectors on the assigned device, starting at sector
+    /// `start_sector`.
+    ///
+    /// This method is handy to erase a stubborn old partition table before calling
+    /// [`Fdisk::partition_table_create`].
+    ///
+    /// **Warning:** unlike the signature wiping set by
+    /// [`FdiskBuilder::wipe_device_metadata`], which is deferred until the partition table is
+    /// written to disk, zeros are written **immediately** to the device.
+    ///
+    /// **Note:** the device must be assigned in read-write mode, and the region must fit
+    /// entirely within the device's boundaries.
+    pub fn zero_device_region(&mut self, start_sector: u64, count: u64) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::zero_device_region zeroing {:?} sectors starting at sector: {:?}",
+            count,
+            start_sector
+        );
+
+        if self.device_is_read_only() {
+            let err_msg = "can not zero a region of a read-only device".to_owned();
+            log::debug!("Fdisk::zero_device_region {}", err_msg);
+
+            return Err(FdiskError::IoError(err_msg));
+        }
+
+        let device_size = self.device_size_in_sectors();
+        let out_of_bounds = start_sector
+            .checked_add(count)
+            .map(|end| end > device_size)
+            .unwrap_or(true);
+
+        if out_of_bounds {
+            let err_msg = format!(
+                "region of {:?} sectors starting at sector {:?} exceeds device size ({:?} sectors)",
+                count, start_sector, device_size
+            );
+            log::debug!("Fdisk::zero_device_region {}", err_msg);
+
+            return Err(FdiskError::ResultOutOfRange(err_msg));
+        }
+
+        let sector_size = self.device_bytes_per_logical_sector();
+        let fd = unsafe { libfdisk::fdisk_get_devfd(self.inner) };
+        if fd < 0 {
+            let err_msg = "failed to zero device region. Device is not open".to_owned();
+            log::debug!(
+                "Fdisk::zero_device_region {}. libfdisk::fdisk_get_devfd returned error code: {:?}",
+                err_msg,
+                fd
+            );
+
+            return Err(FdiskError::IoError(err_msg));
+        }
+
+        // Write one sector at a time to keep the buffer small.
+        let zeros = vec![0u8; sector_size as usize];
+
+        for sector in start_sector..(start_sector + count) {
+            let offset = (sector * sector_size) as libc::off_t;
+            let mut written = 0;
+
+            while written < zeros.len() {
+                let result = unsafe {
+                    libc::pwrite(
+                        fd,
+                        zeros[written..].as_ptr() as *const libc::c_void,
+                        zeros.len() - written,
+                        offset + written as libc::off_t,
+                    )
+                };
+
+                match result {
+                    n if n < 0 => {
+                        let err = std::io::Error::last_os_error();
+                        if err.kind() == std::io::ErrorKind::Interrupted {
+                            continue;
+                        }
+
+                        let err_msg = format!("failed to zero sector {:?} {}", sector, err);
+                        log::debug!(
+                            "Fdisk::zero_device_region {}. libc::pwrite returned error code: {:?}",
+                            err_msg,
+                            n
+                        );
+
+                        return Err(FdiskError::IoError(err_msg));
+                    }
+                    0 => {
+                        let err_msg = format!(
+                            "failed to zero sector {:?}. No byte written at offset {:?}",
+                            sector,
+                            offset + written as libc::off_t
+                        );
+                        log::debug!("Fdisk::zero_device_region {}", err_msg);
+
+                        return Err(FdiskError::IoError(err_msg));
+                    }
+                    n => written += n as usize,
+                }
+            }
+        }
+
+        log::debug!(
+            "Fdisk::zero_device_region zeroed {:?} sectors starting at sector: {:?}",
+            count,
+            start_sector
+        );
+
+        Ok(())
+    }
+
     /// Prints an `info`-level log message to the console.
     pub fn log_info<T>(&mut self, message: T) -> Result<(), FdiskError>
     where
@@ -1249,7 +1684,14 @@ fn add_partition(
                 Ok(partition_number)
             }
             code => {
-                let err_msg = "failed to add new partition".to_owned();
+                let err_msg = match -code {
+                    libc::EINVAL => "failed to add new partition: no partition table, or invalid partition template".to_owned(),
+                    libc::ENOSPC => "failed to add new partition: no free space available".to_owned(),
+                    libc::ERANGE => "failed to add new partition: partition out of device range".to_owned(),
+                    libc::ENOSYS => "failed to add new partition: operation not supported by partition table".to_owned(),
+                    libc::EBUSY => "failed to add new partition: partition number already in use".to_owned(),
+                    _ => "failed to add new partition".to_owned(),
+                };
                 log::debug!("Fdisk::add_partition {}. libfdisk::fdisk_add_partition returned error code: {:?}", err_msg, code);
 
                 Err(FdiskError::Config(err_msg))
@@ -1282,6 +1724,176 @@ pub fn partition_add(&mut self, partition: Partition) -> Result<usize, FdiskErro
         Self::add_partition(self.inner, partition.inner)
     }
 
+    /// Adds a new partition to the in-memory partition table held by this `Fdisk`, like
+    /// [`Fdisk::partition_add`], and marks the area it covers for metadata erasure (see
+    /// [`Fdisk::partition_wipe_activate`]). Any file system or partition table signature found in
+    /// that area is wiped when the partition table is written to disk.
+    ///
+    /// `libfdisk` keeps track of areas to wipe in the `Fdisk`, not in the [`Partition`]s
+    /// themselves; use [`Fdisk::is_partition_wipe_active`] to check whether a partition's area
+    /// will be wiped.
+    ///
+    /// If the partition can not be marked for erasure, it is removed from the partition table
+    /// before this method returns an error.
+    pub fn partition_add_wiping_signatures(
+        &mut self,
+        partition: Partition,
+    ) -> Result<usize, FdiskError> {
+        log::debug!(
+            "Fdisk::partition_add_wiping_signatures adding a new partition with signature wipe"
+        );
+
+        let partition_number = Self::add_partition(self.inner, partition.inner)?;
+
+        if let Err(e) = Self::wipe_partition(self.inner, partition_number, true) {
+            let _ = self.partition_delete(partition_number);
+            log::debug!("Fdisk::partition_add_wiping_signatures {}", e);
+
+            return Err(e);
+        }
+
+        Ok(partition_number)
+    }
+
+    /// Adds a new partition to the in-memory partition table held by this `Fdisk`, placing it at
+    /// the beginning of the `index`-th free region on the assigned device, as listed by
+    /// [`Fdisk::list_empty_spaces`].
+    ///
+    /// The partition's starting sector is aligned on the first grain boundary within the free
+    /// region (see [`Fdisk::device_grain_size`]), overriding any starting sector set on
+    /// `partition`. If `partition` has no size, it will extend to the end of the free region.
+    ///
+    /// Returns an error if there is no free region at `index`.
+    pub fn partition_add_in_free_region(
+        &mut self,
+        mut partition: Partition,
+        index: usize,
+    ) -> Result<usize, FdiskError> {
+        log::debug!(
+            "Fdisk::partition_add_in_free_region adding a new partition in free region: {:?}",
+            index
+        );
+
+        let region = self.list_empty_spaces().and_then(|regions| {
+            regions
+                .get(index)
+                .and_then(|region| region.starting_sector().zip(region.ending_sector()))
+        });
+
+        let (start, end) = region.ok_or_else(|| {
+            let err_msg = format!("no free region at index: {:?}", index);
+            log::debug!("Fdisk::partition_add_in_free_region {}", err_msg);
+
+            FdiskError::ResultOutOfRange(err_msg)
+        })?;
+
+        let aligned_start =
+            unsafe { libfdisk::fdisk_align_lba_in_range(self.inner, start, start, end) };
+
+        partition.set_starting_sector(aligned_start).map_err(|e| {
+            let err_msg = format!("failed to set partition's starting sector. {}", e);
+            FdiskError::Config(err_msg)
+        })?;
+
+        Self::add_partition(self.inner, partition.inner)
+    }
+
+    /// Adds a new partition to the in-memory partition table held by this `Fdisk`, aligning its
+    /// starting sector on the boundary described by `target`, instead of the device-wide grain
+    /// (see [`Fdisk::device_grain_size`]).
+    ///
+    /// If `partition` has a starting sector, it is rounded up to the next boundary. Otherwise,
+    /// the partition starts on the first boundary, within a free region (see
+    /// [`Fdisk::list_empty_spaces`]), where it fits. If `partition` has no size, it will extend to
+    /// the end of that free region.
+    ///
+    /// Returns a [`FdiskError::DataAlignment`] error if the alignment requested is not a non-zero
+    /// multiple of the device's logical sector size, or a [`FdiskError::ResultOutOfRange`] error if
+    /// no free region can hold the aligned partition.
+    pub fn partition_add_aligned(
+        &mut self,
+        mut partition: Partition,
+        target: AlignmentTarget,
+    ) -> Result<usize, FdiskError> {
+        log::debug!(
+            "Fdisk::partition_add_aligned adding a new partition aligned on: {:?}",
+            target
+        );
+
+        let sector_size = self.device_bytes_per_logical_sector();
+        let alignment = match target {
+            AlignmentTarget::Grain => self.device_grain_size(),
+            AlignmentTarget::PhysicalSector => self.device_bytes_per_physical_sector(),
+            AlignmentTarget::OptimalIo => self.device_optimal_io_size(),
+            AlignmentTarget::Bytes(bytes) => bytes,
+        };
+
+        if alignment < sector_size || alignment % sector_size != 0 {
+            let err_msg = format!(
+                "alignment of {:?} bytes is not a multiple of the logical sector size ({:?} bytes)",
+                alignment, sector_size
+            );
+            log::debug!("Fdisk::partition_add_aligned {}", err_msg);
+
+            return Err(FdiskError::DataAlignment(err_msg));
+        }
+
+        let alignment = alignment / sector_size;
+        let align_up = |lba: u64| lba.div_ceil(alignment).checked_mul(alignment);
+
+        let start = match partition.starting_sector() {
+            Some(start) if !partition.uses_default_starting_sector() => align_up(start)
+                .ok_or_else(|| {
+                    let err_msg = format!(
+                        "starting sector {:?} aligned on {:?} sectors is out of range",
+                        start, alignment
+                    );
+                    log::debug!("Fdisk::partition_add_aligned {}", err_msg);
+
+                    FdiskError::ResultOutOfRange(err_msg)
+                })?,
+            _ => {
+                let size = partition.size_in_sectors();
+
+                let start = self.list_empty_spaces().and_then(|regions| {
+                    regions.iter().find_map(|region| {
+                        let (start, end) = region.starting_sector().zip(region.ending_sector())?;
+                        let aligned_start = align_up(start)?;
+
+                        let fits = match size {
+                            Some(size) => aligned_start
+                                .checked_add(size.saturating_sub(1))
+                                .is_some_and(|last| last <= end),
+                            // Without a size, the partition extends to the end of the region.
+                            None => aligned_start <= end,
+                        };
+
+                        fits.then_some(aligned_start)
+                    })
+                });
+
+                start.ok_or_else(|| {
+                    let err_msg = format!(
+                        "no free region can hold a partition aligned on {:?} sectors",
+                        alignment
+                    );
+                    log::debug!("Fdisk::partition_add_aligned {}", err_msg);
+
+                    FdiskError::ResultOutOfRange(err_msg)
+                })?
+            }
+        };
+
+        partition.set_starting_sector(start).map_err(|e| {
+            let err_msg = format!("failed to set partition's starting sector. {}", e);
+            log::debug!("Fdisk::partition_add_aligned {}", err_msg);
+
+            FdiskError::Config(err_msg)
+        })?;
+
+        Self::add_partition(self.inner, partition.inner)
+    }
+
     /// Adds a new partition to the partition table to be created by this `Fdisk`. This
     /// operation is **interactive**, using [`Prompt`](crate::core::prompt::Prompt)s to collect the
     /// partition's parameters.
@@ -1317,14 +1929,55 @@ pub fn partitions_append(&self, partitions: PartitionList) -> Result<(), FdiskEr
         }
     }
 
+    /// Adds each [`Partition`] in `list` to this `Fdisk`'s in-memory partition table, in order.
+    ///
+    /// Like [`Fdisk::partition_add`], this method respects the partition numbers, starting
+    /// sectors and sizes set on each entry, and falls back to default values (or interactively
+    /// asks for them) when they are not set. Unlike [`Fdisk::partitions_append`], it does not skip
+    /// entries lacking a starting sector.
+    ///
+    /// Stops at the first entry that can not be added, and returns an error naming its index in
+    /// `list`; the entries before it remain in the partition table.
+    pub fn apply_table(&mut self, list: &PartitionList) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::apply_table adding {:?} partitions to the partition table",
+            list.len()
+        );
+
+        for (index, partition) in list.iter().enumerate() {
+            Self::add_partition(self.inner, partition.inner).map_err(|e| {
+                let err_msg = format!(
+                    "failed to add partition list entry at index {:?}. {}",
+                    index, e
+                );
+                log::debug!("Fdisk::apply_table {}", err_msg);
+
+                FdiskError::Config(err_msg)
+            })?;
+        }
+
+        log::debug!("Fdisk::apply_table added partitions to the partition table");
+
+        Ok(())
+    }
+
     /// Deletes a partition with the given identification number from the partition table on the
     /// device assigned to this `Fdisk`.
+    ///
+    /// Returns a [`FdiskError::NoSuchPartition`] error if no partition uses `partition_number`.
     pub fn partition_delete(&mut self, partition_number: usize) -> Result<(), FdiskError> {
         log::debug!(
             "Fdisk::partition_delete deleting partition with number: {:?}",
             partition_number
         );
 
+        if !self.partition_is_number_in_use(partition_number) {
+            let err_msg = format!("no partition with number: {:?}", partition_number);
+            log::debug!("Fdisk::partition_delete {}", err_msg);
+
+            return Err(FdiskError::NoSuchPartition(err_msg));
+        }
+
         let result = unsafe { libfdisk::fdisk_delete_partition(self.inner, partition_number) };
 
         match result {
@@ -1347,6 +2000,49 @@ pub fn partition_delete(&mut self, partition_number: usize) -> Result<(), FdiskE
         }
     }
 
+    /// Deletes the partitions with the given identification numbers from the partition table on
+    /// the device assigned to this `Fdisk`.
+    ///
+    /// Before deleting anything, this method checks that each number in `partition_numbers`
+    /// refers to a partition in use; if one does not, it returns a
+    /// [`FdiskError::NoSuchPartition`] error and leaves the
+    /// partition table untouched. Duplicate numbers are ignored.
+    ///
+    /// **Note:** partitions are deleted from the highest number to the lowest, which protects
+    /// against renumbering. In an `MBR` partition table, deleting a logical partition shifts
+    /// the numbers of all logical partitions after it down by one (e.g. after deleting
+    /// partition `5`, partition `6` becomes partition `5`). Thus, calling
+    /// [`Fdisk::partition_delete`] in a loop over increasing numbers deletes the wrong entries.
+    /// `GPT` partition tables do not renumber entries on deletion, the deletion order has no
+    /// effect there.
+    pub fn delete_partitions(&mut self, partition_numbers: &[usize]) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::delete_partitions deleting partitions with numbers: {:?}",
+            partition_numbers
+        );
+
+        let mut numbers = partition_numbers.to_vec();
+        numbers.sort_unstable();
+        numbers.dedup();
+
+        if let Some(number) = numbers
+            .iter()
+            .find(|&&number| !self.partition_is_number_in_use(number))
+        {
+            let err_msg = format!("no partition with number: {:?}", number);
+            log::debug!("Fdisk::delete_partitions {}", err_msg);
+
+            return Err(FdiskError::NoSuchPartition(err_msg));
+        }
+
+        // Delete from highest to lowest number to avoid renumbering issues.
+        for number in numbers.into_iter().rev() {
+            self.partition_delete(number)?;
+        }
+
+        Ok(())
+    }
+
     /// Deletes all partitions in the partition table on the device assigned to this `Fdisk`.
     pub fn partition_delete_all(&mut self) -> Result<(), FdiskError> {
         log::debug!("Fdisk::partition_delete_all deleting all partitions");
@@ -1371,7 +2067,7 @@ pub fn partition_delete_all(&mut self) -> Result<(), FdiskError> {
     #[doc(hidden)]
     /// Returns the next partition's number.
     fn next_partition_number(
-        ptr: &mut Self,
+        ptr: &Self,
         partition: *mut libfdisk::fdisk_partition,
     ) -> Result<usize, FdiskError> {
         let mut number = MaybeUninit::<libc::size_t>::zeroed();
@@ -1429,14 +2125,110 @@ pub fn partition_next_number(&mut self, partition: &Partition) -> Result<usize,
         Self::next_partition_number(self, partition.inner)
     }
 
-    /// Overrides the configuration of the partition with identification number matching
-    /// `partition_number` with the `template`'s parameters.
-    pub fn partition_override_settings(
-        &mut self,
-        partition_number: usize,
+    /// Returns the partition number that [`Fdisk::partition_add`] would assign to a partition
+    /// created from the `template`, or `None` if the partition table is full.
+    ///
+    /// If the `template` has a partition number, that number is returned when it is free; a
+    /// [`FdiskError::ResultOutOfRange`] error is returned when it is already in use, or beyond
+    /// the partition table's capacity. Otherwise, the first free partition number is returned.
+    ///
+    /// **Note:** templates created with [`PartitionBuilder`](crate::core::partition::PartitionBuilder)
+    /// either have a partition number, or use the first free one. For other templates,
+    /// `libfdisk` may prompt the user for a number (see [`Fdisk::partition_next_number`]).
+    pub fn next_free_partition_number(
+        &self,
         template: &Partition,
-    ) -> Result<(), FdiskError> {
-        log::debug!("Fdisk::partition_override_settings overriding partition settings");
+    ) -> Result<Option<usize>, FdiskError> {
+        log::debug!("Fdisk::next_free_partition_number getting next free partition number");
+
+        match Self::next_partition_number(self, template.inner) {
+            Ok(number) => Ok(Some(number)),
+            Err(FdiskError::NoNextPartitionNumber(_)) => {
+                log::debug!("Fdisk::next_free_partition_number partition table is full");
+
+                Ok(None)
+            }
+            // `libfdisk` returns `-ERANGE` when it finds no free partition number.
+            Err(FdiskError::ResultOutOfRange(_)) if template.number().is_none() => {
+                log::debug!("Fdisk::next_free_partition_number partition table is full");
+
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the lowest unused partition number, paired with the starting sector and size (in
+    /// sectors) of the largest unallocated region on the assigned device, or `None` if the
+    /// partition table is full or the device has no free space.
+    ///
+    /// Free regions are those listed by [`Fdisk::list_empty_spaces`], i.e. already aligned to
+    /// the device's grain size.
+    ///
+    /// Returns a [`FdiskError::Config`] error if the device has no partition table.
+    ///
+    /// The partition number is the one [`Fdisk::next_free_partition_number`] picks for a template
+    /// without a partition number. For a `DOS` partition table, it may be that of a logical
+    /// partition inside an existing extended partition.
+    pub fn next_free_slot(&self) -> Result<Option<(usize, u64, u64)>, FdiskError> {
+        log::debug!("Fdisk::next_free_slot looking for next free partition slot");
+
+        if !self.device_has_partition_table() {
+            let err_msg =
+                "failed to find next free partition slot. No partition table on device".to_owned();
+            log::debug!("Fdisk::next_free_slot {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        // Let `libfdisk` pick the number, it knows which logical partitions a `DOS` partition
+        // table can still hold.
+        let template = Partition::builder().build().map_err(|e| {
+            let err_msg = format!("failed to find next free partition slot. {}", e);
+            log::debug!("Fdisk::next_free_slot {}", err_msg);
+
+            FdiskError::Creation(err_msg)
+        })?;
+
+        let number = self.next_free_partition_number(&template)?;
+
+        let region = self.list_empty_spaces().and_then(|spaces| {
+            spaces
+                .iter()
+                .filter_map(|space| Some((space.starting_sector()?, space.size_in_sectors()?)))
+                .filter(|&(_, size)| size > 0)
+                .max_by_key(|&(_, size)| size)
+        });
+
+        let slot = number
+            .zip(region)
+            .map(|(number, (start, size))| (number, start, size));
+        log::debug!("Fdisk::next_free_slot value: {:?}", slot);
+
+        Ok(slot)
+    }
+
+    /// Overrides the configuration of the partition with identification number matching
+    /// `partition_number` with the `template`'s parameters.
+    ///
+    /// Only the parameters set on `template` are modified (type, name, UUID, attributes, starting
+    /// sector, size, etc.), the partition keeps its identification number and position in the
+    /// partition table.
+    ///
+    /// Returns a [`FdiskError::NoSuchPartition`] error if no partition uses `partition_number`.
+    pub fn partition_override_settings(
+        &mut self,
+        partition_number: usize,
+        template: &Partition,
+    ) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::partition_override_settings overriding partition settings");
+
+        if !self.partition_is_number_in_use(partition_number) {
+            let err_msg = format!("no partition with number: {:?}", partition_number);
+            log::debug!("Fdisk::partition_override_settings {}", err_msg);
+
+            return Err(FdiskError::NoSuchPartition(err_msg));
+        }
 
         let result =
             unsafe { libfdisk::fdisk_set_partition(self.inner, partition_number, template.inner) };
@@ -1495,6 +2287,15 @@ fn wipe_partition(
     }
 
     /// Marks all metadata on the [`Partition`] matching the given `partition_number` for deletion.
+    ///
+    /// All file system, RAID, and partition table signatures found in the partition's area are
+    /// erased when the partition table is written to disk, which is handy to scrub a partition
+    /// slot before reusing it. Use [`Fdisk::is_partition_wipe_active`] to check the current
+    /// setting of a partition.
+    ///
+    /// **Note:** this setting is independent from [`FdiskBuilder::wipe_device_metadata`], which
+    /// only applies to the signatures found on the whole device when a new partition table is
+    /// created.
     pub fn partition_wipe_activate(&mut self, partition_number: usize) -> Result<(), FdiskError> {
         log::debug!("Fdisk::partition_wipe_activate enabling partition wipe");
 
@@ -1503,8 +2304,12 @@ pub fn partition_wipe_activate(&mut self, partition_number: usize) -> Result<(),
 
     /// Marks all metadata on the [`Partition`] matching the given `partition_number` for
     /// preservation.
+    ///
+    /// **Note:** this setting is independent from [`FdiskBuilder::wipe_device_metadata`].
+    /// Disabling the wipe of a partition does not prevent the builder-level option from erasing
+    /// signatures in the same area.
     pub fn partition_wipe_deactivate(&mut self, partition_number: usize) -> Result<(), FdiskError> {
-        log::debug!("Fdisk::partition_wipe_deactivate enabling partition wipe");
+        log::debug!("Fdisk::partition_wipe_deactivate disabling partition wipe");
 
         Self::wipe_partition(self.inner, partition_number, false)
     }
@@ -1524,7 +2329,18 @@ fn create_partition_table(
                 Ok(())
             }
             code => {
-                let err_msg = "failed to create partition table".to_owned();
+                let err_msg = match -code {
+                    libc::EINVAL => {
+                        "failed to create partition table: unknown or disabled partition table type"
+                            .to_owned()
+                    }
+                    libc::ENOSYS => {
+                        "failed to create partition table: operation not supported by partition table type"
+                            .to_owned()
+                    }
+                    libc::ENOMEM => "failed to create partition table: out of memory".to_owned(),
+                    _ => "failed to create partition table".to_owned(),
+                };
                 log::debug!("Fdisk::create_partition_table {}. libfdisk::fdisk_create_disklabel returned error code: {:?}", err_msg, code);
 
                 Err(FdiskError::Creation(err_msg))
@@ -1541,7 +2357,257 @@ pub fn partition_table_create_default(&mut self) -> Result<(), FdiskError> {
         Self::create_partition_table(self.inner, std::ptr::null())
     }
 
-    /// Creates a partition table.
+    /// Applies `sfdisk`-style partition table headers without going through a [`Script`].
+    ///
+    /// Supported headers are:
+    /// - `label`: partition table type (`bsd`, `dos`, `gpt`, `sgi`, or `sun`); when present,
+    ///   a new partition table of that type is created before the other headers are applied,
+    /// - `label-id`: partition table identifier (see [`Fdisk::partition_table_set_string_id`]),
+    /// - `first-lba`: first usable LBA,
+    /// - `last-lba`: last usable LBA,
+    /// - `table-length`: maximum number of entries in a `GPT` Partition Entry Array.
+    ///
+    /// When creating a `GPT`, `first-lba`, `last-lba`, and `table-length` are recorded in the new
+    /// partition table's Header, as `sfdisk` does. Otherwise, `first-lba` and `last-lba` only set
+    /// the in-memory usable area (see [`Fdisk::device_set_first_lba`] and
+    /// [`Fdisk::device_set_last_lba`]), and `table-length` resizes the Partition Entry Array of
+    /// the current `GPT` (see [`FdiskGPTExt::gpt_set_partition_entry_array_size`]).
+    ///
+    /// All headers are validated before any is applied. Returns a [`FdiskError::Config`] error
+    /// for unknown headers, invalid values, an identifier the partition table does not support,
+    /// or usable LBAs outside the bounds of the device or of the `GPT` to create.
+    pub fn apply_headers(&mut self, headers: &BTreeMap<String, String>) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::apply_headers applying headers: {:?}", headers);
+
+        const KNOWN_HEADERS: [&str; 5] =
+            ["label", "label-id", "first-lba", "last-lba", "table-length"];
+
+        let invalid = |name: &str, value: &str| {
+            let err_msg = format!("invalid value for header {:?}: {:?}", name, value);
+            log::debug!("Fdisk::apply_headers {}", err_msg);
+
+            FdiskError::Config(err_msg)
+        };
+
+        if let Some(name) = headers
+            .keys()
+            .find(|name| !KNOWN_HEADERS.contains(&name.as_str()))
+        {
+            let err_msg = format!("unknown header: {:?}", name);
+            log::debug!("Fdisk::apply_headers {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let kind = headers
+            .get("label")
+            .map(|value| {
+                [
+                    PartitionTableKind::BSD,
+                    PartitionTableKind::DOS,
+                    PartitionTableKind::GPT,
+                    PartitionTableKind::SGI,
+                    PartitionTableKind::SUN,
+                ]
+                .into_iter()
+                .find(|kind| kind.as_str().eq_ignore_ascii_case(value))
+                .ok_or_else(|| invalid("label", value))
+            })
+            .transpose()?;
+
+        let parse_number = |name: &str| {
+            headers
+                .get(name)
+                .map(|value| value.parse::<u64>().map_err(|_| invalid(name, value)))
+                .transpose()
+        };
+
+        let first_lba = parse_number("first-lba")?;
+        let last_lba = parse_number("last-lba")?;
+        let table_length = parse_number("table-length")?
+            .map(|length| {
+                u32::try_from(length).map_err(|_| invalid("table-length", &length.to_string()))
+            })
+            .transpose()?;
+
+        // ---- Validate all headers, before modifying anything.
+
+        // Type of the partition table the headers apply to.
+        let target_kind = kind.or_else(|| self.partition_table_current().map(|table| table.kind()));
+
+        if let Some(id) = headers.get("label-id") {
+            match target_kind {
+                Some(kind @ (PartitionTableKind::GPT | PartitionTableKind::DOS))
+                    if Self::is_valid_partition_table_id(kind, id) => {}
+                _ => return Err(invalid("label-id", id)),
+            }
+        }
+
+        if table_length.is_some() && target_kind != Some(PartitionTableKind::GPT) {
+            let err_msg = "header \"table-length\" only applies to GPT partition tables".to_owned();
+            log::debug!("Fdisk::apply_headers {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        // Usable area bounds.
+        let (min_lba, max_lba) = if kind == Some(PartitionTableKind::GPT) {
+            Self::gpt_usable_lba_bounds(self, table_length.unwrap_or(128))
+        } else {
+            (1, self.device_size_in_sectors().saturating_sub(1))
+        };
+
+        for (name, address) in [("first-lba", first_lba), ("last-lba", last_lba)] {
+            if let Some(address) = address {
+                if address < min_lba || address > max_lba {
+                    return Err(invalid(name, &address.to_string()));
+                }
+            }
+        }
+
+        if let (Some(first), Some(last)) = (first_lba, last_lba) {
+            if first > last {
+                return Err(invalid("last-lba", &last.to_string()));
+            }
+        }
+
+        // ---- Apply headers.
+
+        match kind {
+            Some(PartitionTableKind::GPT) => {
+                // `libfdisk` reads these values from the associated script while creating a `GPT`.
+                let script_headers: Vec<(&str, u64)> = [
+                    ("first-lba", first_lba),
+                    ("last-lba", last_lba),
+                    ("table-length", table_length.map(u64::from)),
+                ]
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| (name, value)))
+                .collect();
+
+                Self::create_partition_table_with_headers(
+                    self,
+                    PartitionTableKind::GPT,
+                    &script_headers,
+                )?;
+            }
+            _ => {
+                if let Some(kind) = kind {
+                    self.partition_table_create(kind)?;
+                }
+
+                if let Some(address) = first_lba {
+                    self.device_set_first_lba(address)?;
+                }
+
+                if let Some(address) = last_lba {
+                    self.device_set_last_lba(address)?;
+                }
+
+                if let Some(length) = table_length {
+                    self.gpt_set_partition_entry_array_size(length)?;
+                }
+            }
+        }
+
+        if let Some(id) = headers.get("label-id") {
+            self.partition_table_set_string_id(id)?;
+        }
+
+        log::debug!("Fdisk::apply_headers applied headers");
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Returns the lowest first usable LBA, and the highest last usable LBA of a `GPT` created on
+    /// the assigned device with a Partition Entry Array of `entries` entries.
+    pub(crate) fn gpt_usable_lba_bounds(ptr: &Self, entries: u32) -> (u64, u64) {
+        // Each entry is 128 bytes long.
+        let sector_size = ptr.device_bytes_per_logical_sector().max(1);
+        let array_sectors = (u64::from(entries) * 128).div_ceil(sector_size);
+
+        // Protective MBR + Header before the array, backup array + backup Header at the end.
+        let first = 2 + array_sectors;
+        let last = ptr
+            .device_size_in_sectors()
+            .saturating_sub(2 + array_sectors);
+
+        (first, last)
+    }
+
+    #[doc(hidden)]
+    /// Creates a new partition table of type `kind`, with the `sfdisk`-style `headers` made
+    /// available to `libfdisk` through a temporary script associated with this `Fdisk`.
+    fn create_partition_table_with_headers(
+        ptr: &mut Self,
+        kind: PartitionTableKind,
+        headers: &[(&str, u64)],
+    ) -> Result<(), FdiskError> {
+        let script = unsafe { libfdisk::fdisk_new_script(ptr.inner) };
+        if script.is_null() {
+            let err_msg = "failed to create a temporary script".to_owned();
+            log::debug!("Fdisk::create_partition_table_with_headers {}. libfdisk::fdisk_new_script returned a NULL pointer", err_msg);
+
+            return Err(FdiskError::Script(err_msg));
+        }
+
+        for (name, value) in headers {
+            let name_cstr = ffi_utils::as_ref_str_to_c_string(name);
+            let value_cstr = ffi_utils::as_ref_str_to_c_string(value.to_string());
+
+            let result = match (name_cstr, value_cstr) {
+                (Ok(name_cstr), Ok(value_cstr)) => unsafe {
+                    libfdisk::fdisk_script_set_header(
+                        script,
+                        name_cstr.as_ptr(),
+                        value_cstr.as_ptr(),
+                    )
+                },
+                _ => -libc::EINVAL,
+            };
+
+            if result != 0 {
+                unsafe { libfdisk::fdisk_unref_script(script) };
+
+                let err_msg = format!("failed to set header {:?} to {:?}", name, value);
+                log::debug!("Fdisk::create_partition_table_with_headers {}. libfdisk::fdisk_script_set_header returned error code: {:?}", err_msg, result);
+
+                return Err(FdiskError::Script(err_msg));
+            }
+        }
+
+        // Keep the script associated by the user, if any, to restore it afterwards.
+        let previous = unsafe { libfdisk::fdisk_get_script(ptr.inner) };
+        if !previous.is_null() {
+            unsafe { libfdisk::fdisk_ref_script(previous) };
+        }
+
+        let result = Self::set_script(ptr, script).and_then(|_| {
+            let kind_cstr = kind.to_c_string();
+            Self::create_partition_table(ptr.inner, kind_cstr.as_ptr())
+        });
+
+        // `fdisk_set_script` holds its own reference to the previous script.
+        let _ = Self::set_script(ptr, previous);
+        unsafe {
+            if !previous.is_null() {
+                libfdisk::fdisk_unref_script(previous);
+            }
+            libfdisk::fdisk_unref_script(script);
+        }
+
+        result
+    }
+
+    /// Creates a new, empty, in-memory partition table of the given `kind` on the assigned device,
+    /// replacing any existing one.
+    ///
+    /// Returns a [`FdiskError::Creation`] error if `kind` was disabled (see
+    /// [`FdiskBuilder::enable_only_labels`]), or is not supported by `libfdisk`.
+    ///
+    /// **Note:** changes are only written to disk after calling
+    /// [`Fdisk::partition_table_write_to_disk`].
     pub fn partition_table_create(&mut self, kind: PartitionTableKind) -> Result<(), FdiskError> {
         log::debug!(
             "Fdisk::partition_table_create creating {:?} partition table",
@@ -1555,6 +2621,204 @@ pub fn partition_table_create(&mut self, kind: PartitionTableKind) -> Result<(),
         Self::create_partition_table(self.inner, kind_cstr.as_ptr())
     }
 
+    /// Creates a new, empty, in-memory partition table on the assigned device, with the same type
+    /// and configuration as `template`.
+    ///
+    /// Only the configuration of `template` is copied, not its partitions. The settings copied
+    /// are:
+    /// - the partition table type (see [`PartitionTable::kind`]),
+    /// - for `DOS` partition tables, whether the deprecated `DOS` compatible mode is enabled
+    /// (see [`PartitionTableDOSExt::is_dos_compatible`]).
+    ///
+    /// Geometry requirements (see [`PartitionTable::geometry_cylinders`], etc.) are fixed for
+    /// each partition table type, so they are identical by construction. `GPT` backup header
+    /// placement settings (see [`PartitionTableGPTExt`](crate::core::partition_table::PartitionTableGPTExt))
+    /// are **not** copied, `libfdisk` does not provide a way to read them. Neither are the
+    /// partition table's identifier, or any header entry tied to the template's device.
+    ///
+    /// **Note:** changes are only written to disk after calling
+    /// [`Fdisk::partition_table_write_to_disk`].
+    pub fn adopt_partition_table(&mut self, template: &PartitionTable) -> Result<(), FdiskError> {
+        let kind = template.kind();
+        log::debug!(
+            "Fdisk::adopt_partition_table creating {:?} partition table from template",
+            kind
+        );
+
+        if kind == PartitionTableKind::DOS {
+            let compatible = template.is_dos_compatible();
+
+            if let Some(table) = self.partition_table_by_type_mut(PartitionTableKind::DOS) {
+                let result = if compatible {
+                    table.dos_enable_compatible_mode()
+                } else {
+                    table.dos_disable_compatible_mode()
+                };
+
+                result.map_err(|e| {
+                    let err_msg = format!("failed to copy DOS compatible mode. {e}");
+                    log::debug!("Fdisk::adopt_partition_table {}", err_msg);
+
+                    FdiskError::Config(err_msg)
+                })?;
+            }
+        }
+
+        self.partition_table_create(kind)
+    }
+
+    /// Replaces the `GPT` partition table on the assigned device with a new, empty, in-memory one
+    /// that keeps the same disk GUID.
+    ///
+    /// Recreating a partition table normally generates a new disk GUID, which breaks any
+    /// reference to it (e.g. in boot loader configuration files).
+    ///
+    /// Returns a [`FdiskError::Config`] error if the current partition table is not a `GPT`.
+    ///
+    /// **Note:** changes are only written to disk after calling
+    /// [`Fdisk::partition_table_write_to_disk`].
+    pub fn recreate_gpt_preserving_id(&mut self) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::recreate_gpt_preserving_id recreating GPT partition table");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            let err_msg = "no GPT partition table on device".to_owned();
+            log::debug!("Fdisk::recreate_gpt_preserving_id {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let disk_guid = self.partition_table_id().ok_or_else(|| {
+            let err_msg = "failed to get GPT disk GUID".to_owned();
+            log::debug!("Fdisk::recreate_gpt_preserving_id {}", err_msg);
+
+            FdiskError::Config(err_msg)
+        })?;
+
+        self.partition_table_create(PartitionTableKind::GPT)?;
+        self.partition_table_set_string_id(&disk_guid)?;
+        log::debug!(
+            "Fdisk::recreate_gpt_preserving_id recreated GPT partition table with disk GUID: {:?}",
+            disk_guid
+        );
+
+        Ok(())
+    }
+
+    /// Replaces the disk GUID of the `GPT` partition table on the assigned device with a random
+    /// version 4 UUID, and returns it in string form (e.g.
+    /// `"3F2504E0-4F89-41D3-9A0C-0305E82C3301"`).
+    ///
+    /// Unlike [`Fdisk::partition_table_set_id`], this method does not prompt the user. The UUID's
+    /// random bits are read from the kernel's random number generator (see `getrandom(2)`).
+    ///
+    /// Returns a [`FdiskError::Config`] error if the current partition table is not a `GPT`.
+    ///
+    /// **Note:** changes are only written to disk after calling
+    /// [`Fdisk::partition_table_write_to_disk`].
+    pub fn randomize_gpt_disk_guid(&mut self) -> Result<String, FdiskError> {
+        log::debug!("Fdisk::randomize_gpt_disk_guid generating a new disk GUID");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            let err_msg = "no GPT partition table on device".to_owned();
+            log::debug!("Fdisk::randomize_gpt_disk_guid {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let disk_guid = Self::random_uuid_v4()?;
+        self.partition_table_set_string_id(&disk_guid)?;
+        log::debug!(
+            "Fdisk::randomize_gpt_disk_guid set disk GUID to: {:?}",
+            disk_guid
+        );
+
+        Ok(disk_guid)
+    }
+
+    #[doc(hidden)]
+    /// Generates a random version 4 UUID (RFC 9562), in upper-case string form.
+    fn random_uuid_v4() -> Result<String, FdiskError> {
+        let mut bytes = [0u8; 16];
+        let mut filled = 0;
+
+        while filled < bytes.len() {
+            let result = unsafe {
+                libc::getrandom(
+                    bytes[filled..].as_mut_ptr() as *mut _,
+                    bytes.len() - filled,
+                    0,
+                )
+            };
+
+            match result {
+                n if n >= 0 => filled += n as usize,
+                _ => {
+                    let err = std::io::Error::last_os_error();
+                    if err.kind() == std::io::ErrorKind::Interrupted {
+                        continue;
+                    }
+
+                    let err_msg = format!("failed to generate random UUID {}", err);
+                    log::debug!("Fdisk::random_uuid_v4 {}", err_msg);
+
+                    return Err(FdiskError::IoError(err_msg));
+                }
+            }
+        }
+
+        // Version 4 (random) in the high nibble of byte 6, RFC 4122 variant in the two high bits
+        // of byte 8.
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let hex: String = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+        let uuid = format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        );
+
+        Ok(uuid)
+    }
+
+    /// Creates a partition table of the given `kind`, marking any file system or partition table
+    /// signature detected on the assigned device for erasure (see
+    /// [`Fdisk::device_has_collisions`]).
+    ///
+    /// The signatures are removed when the new partition table is written to disk.
+    ///
+    /// **Note:** returns an error if the assigned device is in use (e.g. one of its partitions is
+    /// mounted).
+    pub fn wipe_collisions_and_create(
+        &mut self,
+        kind: PartitionTableKind,
+    ) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::wipe_collisions_and_create creating {:?} partition table",
+            kind
+        );
+
+        if self.device_is_in_use() {
+            let err_msg = "can not reuse a device in use".to_owned();
+            log::debug!("Fdisk::wipe_collisions_and_create {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        if self.device_has_collisions() {
+            log::debug!(
+                "Fdisk::wipe_collisions_and_create collisions detected: {:?}",
+                self.device_describe_collisions()
+            );
+            self.enable_metadata_wipe()?;
+        }
+
+        self.partition_table_create(kind)
+    }
+
     /// Prints all entries in the Partition Table Header on the assigned device. The data displayed
     /// does not include details about each partition.
     ///
@@ -1583,8 +2847,93 @@ pub fn partition_table_display_details(&self) -> Result<(), FdiskError> {
         }
     }
 
-    /// Sorts partitions in the Partition Entry Array by their starting sectors, in increasing order.
-    pub fn partition_table_sort_partitions(&mut self) -> Result<(), FdiskError> {
+    /// Renumbers the partitions in a `GPT` partition table so that their identification numbers
+    /// are contiguous, starting at `0`, while keeping their relative order.
+    ///
+    /// For example, partitions numbered `0`, `2`, and `5` are renumbered `0`, `1`, and `2`. Each
+    /// partition keeps its location, size, type, name, UUID, and attribute bits.
+    ///
+    /// Unlike [`Fdisk::partition_table_sort_partitions`], which orders partitions by starting
+    /// sector, this method preserves the order of partition numbers; a partition numbered before
+    /// another stays before it, wherever it is on disk.
+    ///
+    /// **Note:** this operation is not atomic. Partitions are renumbered one at a time; if
+    /// renumbering one fails, the partitions renumbered before it keep their new numbers, and the
+    /// failing partition is restored under its original number (the returned error mentions it
+    /// when restoring fails too).
+    ///
+    /// Returns a [`FdiskError::Config`] error if the assigned device's partition table is not a
+    /// `GPT`, or if a partition could not be renumbered.
+    pub fn compact_partition_numbers(&mut self) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::compact_partition_numbers closing gaps between partition numbers");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            let err_msg = "can only renumber partitions in a GPT partition table".to_owned();
+            log::debug!("Fdisk::compact_partition_numbers {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let mut numbers: Vec<usize> = self
+            .used_partitions()?
+            .iter()
+            .filter_map(|partition| partition.number())
+            .collect();
+        numbers.sort_unstable();
+
+        for (target, number) in numbers.into_iter().enumerate() {
+            if target == number {
+                continue;
+            }
+
+            let original = self.get_partition(number)?;
+            let mut moved = original.duplicate().map_err(|e| {
+                let err_msg = format!("failed to copy partition {:?}. {}", number, e);
+                log::debug!("Fdisk::compact_partition_numbers {}", err_msg);
+
+                FdiskError::Config(err_msg)
+            })?;
+            moved.set_partition_number(target).map_err(|e| {
+                let err_msg = format!("failed to renumber partition {:?}. {}", number, e);
+                log::debug!("Fdisk::compact_partition_numbers {}", err_msg);
+
+                FdiskError::Config(err_msg)
+            })?;
+
+            self.partition_delete(number)?;
+
+            if let Err(e) = self.partition_add(moved) {
+                // Put the partition back where it was.
+                let err_msg = match self.partition_add(original) {
+                    Ok(_) => format!(
+                        "failed to renumber partition {:?} to {:?}. {}",
+                        number, target, e
+                    ),
+                    Err(rollback) => format!(
+                        "failed to renumber partition {:?} to {:?}. {}. Failed to restore partition {:?}, it is missing from the partition table. {}",
+                        number, target, e, number, rollback
+                    ),
+                };
+                log::debug!("Fdisk::compact_partition_numbers {}", err_msg);
+
+                return Err(FdiskError::Config(err_msg));
+            }
+
+            log::debug!(
+                "Fdisk::compact_partition_numbers renumbered partition {:?} to {:?}",
+                number,
+                target
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Sorts partitions in the Partition Entry Array by their starting sectors, in increasing order,
+    /// renumbering them accordingly.
+    ///
+    /// Returns `true` if any partition was moved, `false` if the partitions were already sorted.
+    pub fn partition_table_sort_partitions(&mut self) -> Result<bool, FdiskError> {
         log::debug!("Fdisk::partition_table_sort_partitions sorting partition array entries");
 
         let result = unsafe { libfdisk::fdisk_reorder_partitions(self.inner) };
@@ -1595,7 +2944,14 @@ pub fn partition_table_sort_partitions(&mut self) -> Result<(), FdiskError> {
                     "Fdisk::partition_table_sort_partitions sorted partition array entries"
                 );
 
-                Ok(())
+                Ok(true)
+            }
+            1 => {
+                log::debug!(
+                    "Fdisk::partition_table_sort_partitions partition array entries already sorted"
+                );
+
+                Ok(false)
             }
             code => {
                 let err_msg = "failed to sort partition array entries".to_owned();
@@ -1627,12 +2983,58 @@ pub fn partition_table_set_id(&mut self) -> Result<(), FdiskError> {
         }
     }
 
+    #[doc(hidden)]
+    /// Returns `true` if `id` is a well-formed identifier for a partition table of type `kind`,
+    /// i.e. a UUID for a `GPT`, or a 32-bit hexadecimal number (with or without a `0x` prefix)
+    /// for a `DOS` partition table.
+    fn is_valid_partition_table_id(kind: PartitionTableKind, id: &str) -> bool {
+        match kind {
+            PartitionTableKind::GPT => {
+                let groups: Vec<&str> = id.split('-').collect();
+
+                groups.len() == 5
+                    && groups.iter().zip([8, 4, 4, 4, 12]).all(|(group, len)| {
+                        group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit())
+                    })
+            }
+            PartitionTableKind::DOS => {
+                let digits = id
+                    .strip_prefix("0x")
+                    .or_else(|| id.strip_prefix("0X"))
+                    .unwrap_or(id);
+
+                (1..=8).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_hexdigit())
+            }
+            // Other partition tables do not support custom identifiers, `libfdisk` will report
+            // the error.
+            _ => true,
+        }
+    }
+
     /// Sets the partition tables string unique identifier.
+    ///
+    /// The identifier must be a UUID (e.g. `"DD27F98D-7519-4C9E-8041-F2BFA7B1EF61"`) for a `GPT`,
+    /// or a 32-bit hexadecimal number (e.g. `"0x1234abcd"`) for a `DOS` partition table; this
+    /// method returns a [`FdiskError::Config`] error otherwise.
     pub fn partition_table_set_string_id<T>(&self, id: T) -> Result<(), FdiskError>
     where
         T: AsRef<str>,
     {
         let id = id.as_ref();
+
+        let kind = [PartitionTableKind::GPT, PartitionTableKind::DOS]
+            .into_iter()
+            .find(|&kind| self.partition_table_is_of_type(kind));
+
+        if let Some(kind) = kind {
+            if !Self::is_valid_partition_table_id(kind, id) {
+                let err_msg = format!("invalid {} partition table ID: {:?}", kind, id);
+                log::debug!("Fdisk::partition_table_set_string_id {}", err_msg);
+
+                return Err(FdiskError::Config(err_msg));
+            }
+        }
+
         let id_cstr = ffi_utils::as_ref_str_to_c_string(id).map_err(|e| {
             let err_msg = format!("failed to convert value to `CString` {e}");
             FdiskError::CStringConversion(err_msg)
@@ -1696,18 +3098,118 @@ pub fn partition_table_set_partition_type(
         }
     }
 
-    /// Toggles the `bit` flag of the partition with `partition_number`.
-    pub fn partition_table_toggle_partition_flag(
-        &mut self,
-        partition_number: usize,
-        bit: BitFlag,
-    ) -> Result<(), FdiskError> {
-        log::debug!(
-            "Fdisk::partition_table_toggle_partition_flag toggling flag: {:?} of partition: {:?}",
-            bit,
-            partition_number
+    /// Rewrites the types of partitions in a `GPT` partition table.
+    ///
+    /// Calls `f` with the type [`Guid`] of each partition, and sets the partition's type to the
+    /// value returned by `f` if it is `Some`. Partitions with a type that does not match a known
+    /// [`Guid`] are skipped.
+    ///
+    /// Returns the number of partitions whose type changed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rsfdisk::fdisk::Fdisk;
+    /// use rsfdisk::partition::Guid;
+    ///
+    /// fn main() -> rsfdisk::Result<()> {
+    ///     let mut disk = Fdisk::builder()
+    ///         .assign_device("/dev/vda")
+    ///         .enable_read_write()
+    ///         .build()?;
+    ///
+    ///     // Migrate generic Linux data partitions to x86-64 root partitions.
+    ///     let changed = disk.map_partition_types(|guid| match guid {
+    ///         Guid::LinuxData => Some(Guid::LinuxRootx86_64),
+    ///         _ => None,
+    ///     })?;
+    ///
+    ///     if changed > 0 {
+    ///         disk.partition_table_write_to_disk()?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn map_partition_types<F>(&mut self, mut f: F) -> Result<usize, FdiskError>
+    where
+        F: FnMut(Guid) -> Option<Guid>,
+    {
+        log::debug!("Fdisk::map_partition_types rewriting partition types");
+
+        if !self.partition_table_is_of_type(PartitionTableKind::GPT) {
+            let err_msg = "can only rewrite partition types in a GPT partition table".to_owned();
+            log::debug!("Fdisk::map_partition_types {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let updates: Vec<(usize, Guid)> = match self.list_partitions() {
+            Some(partitions) => partitions
+                .iter()
+                .filter_map(|partition| {
+                    let number = partition.number()?;
+                    let guid = partition
+                        .partition_type()
+                        .and_then(|kind| kind.guid().and_then(|s| s.parse::<Guid>().ok()))?;
+
+                    f(guid)
+                        .filter(|new_guid| *new_guid != guid)
+                        .map(|new_guid| (number, new_guid))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        for (number, guid) in updates.iter() {
+            let kind = PartitionKind::builder().guid(*guid).build().map_err(|e| {
+                let err_msg = format!(
+                    "failed to create partition type from GUID {:?}. {}",
+                    guid, e
+                );
+                FdiskError::Config(err_msg)
+            })?;
+
+            self.partition_table_set_partition_type(*number, kind)?;
+        }
+
+        let count = updates.len();
+        log::debug!(
+            "Fdisk::map_partition_types rewrote {:?} partition types",
+            count
+        );
+
+        Ok(count)
+    }
+
+    /// Toggles the `bit` flag of the partition with `partition_number`.
+    ///
+    /// Each flag only applies to one type of partition table (see
+    /// [`BitFlag::partition_table_kind`]), e.g. [`DOSFlag::Boot`](crate::core::partition::DOSFlag::Boot)
+    /// marks a partition as active on a `DOS` partition table. This method returns a
+    /// [`FdiskError::Config`] error if `bit` does not apply to the current partition table.
+    pub fn partition_table_toggle_partition_flag(
+        &mut self,
+        partition_number: usize,
+        bit: BitFlag,
+    ) -> Result<(), FdiskError> {
+        log::debug!(
+            "Fdisk::partition_table_toggle_partition_flag toggling flag: {:?} of partition: {:?}",
+            bit,
+            partition_number
         );
 
+        let kind = bit.partition_table_kind();
+        if !self.partition_table_is_of_type(kind) {
+            let err_msg = format!(
+                "flag: {:?} only applies to {:?} partition tables",
+                bit, kind
+            );
+            log::debug!("Fdisk::partition_table_toggle_partition_flag {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
         let result = unsafe {
             libfdisk::fdisk_toggle_partition_flag(self.inner, partition_number, bit.to_u64())
         };
@@ -1758,10 +3260,97 @@ pub fn partition_table_check(&self) -> VerificationStatus {
         }
     }
 
+    /// Checks whether a partition table is well-formed, collecting the number of problems found
+    /// and the messages describing them.
+    ///
+    /// Returns an error if `libfdisk` fails to run the verification (e.g. there is no partition
+    /// table on the assigned device).
+    ///
+    /// **Note:** this method temporarily installs its own prompt callback to capture the
+    /// warnings emitted by `libfdisk`, and restores the one set by
+    /// [`Fdisk::set_prompt_callback`], if any, before returning.
+    pub fn verify_partition_table(&self) -> Result<VerificationReport, FdiskError> {
+        log::debug!("Fdisk::verify_partition_table verifying partition table");
+
+        unsafe extern "C" fn collect_messages(
+            _: *mut libfdisk::fdisk_context,
+            ask: *mut libfdisk::fdisk_ask,
+            data: *mut libc::c_void,
+        ) -> libc::c_int {
+            // `Prompt` is a transparent wrapper around a `libfdisk::fdisk_ask` pointer.
+            let prompt = &*(&ask as *const _ as *const Prompt);
+
+            match prompt.kind() {
+                PromptKind::Info | PromptKind::Warn | PromptKind::WarnX => {
+                    let messages = &mut *(data as *mut Vec<String>);
+                    if let Some(message) = prompt.error_message() {
+                        messages.push(message.to_owned());
+                    }
+
+                    0
+                }
+                _ => -libc::EINVAL,
+            }
+        }
+
+        let mut messages: Vec<String> = Vec::new();
+
+        unsafe {
+            libfdisk::fdisk_set_ask(
+                self.inner,
+                Some(collect_messages),
+                &mut messages as *mut _ as *mut libc::c_void,
+            );
+        }
+
+        let result = unsafe { libfdisk::fdisk_verify_disklabel(self.inner) };
+
+        Self::restore_prompt_callback(self);
+
+        match result {
+            code if code < 0 => {
+                let err_msg = "failed to verify partition table".to_owned();
+                log::debug!("Fdisk::verify_partition_table {}. libfdisk::fdisk_verify_disklabel returned error code: {:?}", err_msg, code);
+
+                Err(FdiskError::Config(err_msg))
+            }
+            issue_count => {
+                log::debug!(
+                    "Fdisk::verify_partition_table found {:?} problems",
+                    issue_count
+                );
+
+                Ok(VerificationReport::new(issue_count as usize, messages))
+            }
+        }
+    }
+
     /// Writes the in-memory partition table to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - a [`FdiskError::Config`] error if there is no partition table to write,
+    /// - a [`FdiskError::IoError`] error if the assigned device is open in read-only mode,
+    /// - a [`FdiskError::Save`] error if `libfdisk` fails to write the partition table.
     pub fn partition_table_write_to_disk(&mut self) -> Result<(), FdiskError> {
         log::debug!("Fdisk::partition_table_write_to_disk writing partition table to disk");
 
+        if !self.device_has_partition_table() {
+            let err_msg = "no partition table to write to disk".to_owned();
+            log::debug!("Fdisk::partition_table_write_to_disk {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        if self.device_is_read_only() {
+            let err_msg =
+                "can not write partition table to a device open in read-only mode".to_owned();
+            log::debug!("Fdisk::partition_table_write_to_disk {}", err_msg);
+
+            return Err(FdiskError::IoError(err_msg));
+        }
+
         let result = unsafe { libfdisk::fdisk_write_disklabel(self.inner) };
 
         match result {
@@ -1771,7 +3360,10 @@ pub fn partition_table_write_to_disk(&mut self) -> Result<(), FdiskError> {
                 Ok(())
             }
             code => {
-                let err_msg = "failed to write partition table to disk".to_owned();
+                let err_msg = format!(
+                    "failed to write partition table to disk. {}",
+                    std::io::Error::from_raw_os_error(-code)
+                );
                 log::debug!("Fdisk::partition_table_write_to_disk {}. libfdisk::fdisk_write_disklabel returned error code: {:?}", err_msg, code);
 
                 Err(FdiskError::Save(err_msg))
@@ -1779,7 +3371,169 @@ pub fn partition_table_write_to_disk(&mut self) -> Result<(), FdiskError> {
         }
     }
 
+    #[doc(hidden)]
+    /// Returns the characteristics of each partition in `list` compared by
+    /// [`Fdisk::verify_written`].
+    fn partition_layout(list: &PartitionList) -> Vec<PartitionLayout> {
+        list.iter().map(PartitionLayout::from).collect()
+    }
+
+    #[doc(hidden)]
+    /// Returns a new, read-only, `Fdisk` assigned to the same device, i.e. a view of the
+    /// partition table as it is on disk.
+    fn read_only_copy(&self) -> Result<Fdisk<'a>, FdiskError> {
+        let device_name = self.device_name().ok_or_else(|| {
+            let err_msg = "failed to get assigned device's name".to_owned();
+            log::debug!("Fdisk::read_only_copy {}", err_msg);
+
+            FdiskError::Config(err_msg)
+        })?;
+
+        let mut on_disk = Fdisk::new()?;
+        on_disk.assign_device_read_only(device_name)?;
+
+        Ok(on_disk)
+    }
+
+    /// Returns `true` when the partition table on disk matches the in-memory partition table.
+    ///
+    /// This method reads the partition table from the assigned device through a new, read-only,
+    /// `Fdisk` instance, then compares the type and identifier of both partition tables, and the
+    /// number, starting sector, size, type, name, and UUID of each partition. Call it after
+    /// [`Fdisk::partition_table_write_to_disk`] to check that the partition table was written as
+    /// expected.
+    ///
+    /// **Note:** the partition table is read back through the operating system's page cache, so
+    /// this method can not detect a device that drops or corrupts data it acknowledged writing.
+    pub fn verify_written(&self) -> Result<bool, FdiskError> {
+        log::debug!("Fdisk::verify_written comparing partition table on disk with in-memory copy");
+
+        let on_disk = self.read_only_copy()?;
+
+        let in_memory_kind = self.partition_table_current().map(|table| table.kind());
+        let on_disk_kind = on_disk.partition_table_current().map(|table| table.kind());
+
+        let matches = in_memory_kind == on_disk_kind
+            && self.partition_table_id() == on_disk.partition_table_id()
+            && Self::partition_layout(&self.used_partitions()?)
+                == Self::partition_layout(&on_disk.used_partitions()?);
+        log::debug!("Fdisk::verify_written value: {:?}", matches);
+
+        Ok(matches)
+    }
+
+    /// Safely persists the in-memory partition table: writes it to disk, updates the kernel's
+    /// view of the partitions, then checks that the partition table on disk matches the one in
+    /// memory.
+    ///
+    /// The steps are run in order, and the method stops at the first fatal failure:
+    /// 1. [`Fdisk::partition_table_write_to_disk`]; if it fails, nothing was written and its
+    ///    error is returned as is.
+    /// 2. [`Fdisk::reread_changed_partition_entries`], for block devices only, with the
+    ///    partitions read from disk before writing. An `EBUSY` failure (e.g. a partition is
+    ///    mounted) is **not** fatal: the partition table on disk is up to date, but the kernel will
+    ///    only see the changes after the next reboot or reread. Any other failure is returned as a
+    ///    [`FdiskError::Restore`] error.
+    /// 3. [`Fdisk::verify_written`]; returns a [`FdiskError::Save`] error if the partition table
+    ///    read back from disk differs from the in-memory copy. The write did happen, so the
+    ///    device should be inspected before any further modification.
+    ///
+    /// **Note:** `Fdisk` holds raw `libfdisk` pointers and is neither `Send` nor `Sync`, so this
+    /// method is synchronous; to call it from an async runtime, run it on a dedicated blocking
+    /// thread that owns the `Fdisk`.
+    pub fn commit(&mut self) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::commit persisting partition table");
+
+        let entries_on_disk = if self.device_is_image_file() {
+            None
+        } else {
+            let on_disk = self.read_only_copy()?;
+            match on_disk.list_partitions() {
+                Some(list) => Some(list),
+                None => Some(PartitionList::new().map_err(|e| {
+                    let err_msg = format!("failed to create partition list. {}", e);
+                    log::debug!("Fdisk::commit {}", err_msg);
+
+                    FdiskError::Creation(err_msg)
+                })?),
+            }
+        };
+
+        self.partition_table_write_to_disk()?;
+
+        if let Some(entries_on_disk) = entries_on_disk {
+            match Self::reread_changes(self, &entries_on_disk) {
+                Ok(()) => {
+                    log::debug!("Fdisk::commit updated kernel partition entries");
+                }
+                Err(code) if -code == libc::EBUSY => {
+                    log::debug!("Fdisk::commit partition table written, but the device is busy. The kernel still uses the old partition entries");
+                }
+                Err(code) => {
+                    let err_msg =
+                        "partition table written, but failed to update kernel partition entries"
+                            .to_owned();
+                    log::debug!("Fdisk::commit {}. libfdisk::fdisk_reread_changes failed with error code: {:?}", err_msg, code);
+
+                    return Err(FdiskError::Restore(err_msg));
+                }
+            }
+        }
+
+        if self.verify_written()? {
+            log::debug!("Fdisk::commit persisted partition table");
+
+            Ok(())
+        } else {
+            let err_msg =
+                "partition table on disk does not match the in-memory partition table after writing"
+                    .to_owned();
+            log::debug!("Fdisk::commit {}", err_msg);
+
+            Err(FdiskError::Save(err_msg))
+        }
+    }
+
+    /// Writes the in-memory partition table to disk, calling `f` to report milestones.
+    ///
+    /// `f` receives [`WriteStage::Started`] before writing begins, [`WriteStage::Written`] once
+    /// `libfdisk` has written the partition table, and [`WriteStage::Verified`] once the
+    /// partition table read back from disk matches the in-memory copy (see
+    /// [`Fdisk::verify_written`]). `f` does not receive any stage after a failure.
+    ///
+    /// Returns a [`FdiskError::Save`] error if the partition table read back from disk differs
+    /// from the in-memory copy.
+    pub fn write_partition_table_with<F>(&mut self, mut f: F) -> Result<(), FdiskError>
+    where
+        F: FnMut(WriteStage),
+    {
+        log::debug!("Fdisk::write_partition_table_with writing partition table to disk");
+
+        f(WriteStage::Started);
+
+        self.partition_table_write_to_disk()?;
+
+        f(WriteStage::Written);
+
+        if self.verify_written()? {
+            log::debug!("Fdisk::write_partition_table_with verified partition table on disk");
+            f(WriteStage::Verified);
+
+            Ok(())
+        } else {
+            let err_msg =
+                "partition table on disk does not match the in-memory partition table after writing"
+                    .to_owned();
+            log::debug!("Fdisk::write_partition_table_with {}", err_msg);
+
+            Err(FdiskError::Save(err_msg))
+        }
+    }
+
     /// Returns `true` when the partition table on the assigned disk matches the given type.
+    ///
+    /// Returns `false` when the assigned device has no partition table, it never returns an
+    /// error.
     pub fn partition_table_is_of_type(&self, kind: PartitionTableKind) -> bool {
         let kind_u32: u32 = kind.into();
         let state = unsafe { libfdisk::fdisk_is_labeltype(self.inner, kind_u32) == 1 };
@@ -1871,6 +3625,10 @@ pub fn script_new_from_file<T>(&self, file_path: T) -> Result<&mut Script, Fdisk
     /// table, and partitions its content specifies.
     ///
     /// To write the changes to disk call [`Fdisk::partition_table_write_to_disk`].
+    ///
+    /// **Note:** a [`Script`] borrows the `Fdisk` it was created from. To apply a script read
+    /// with [`Script::new_from_file`], create it from a separate `Fdisk`, for example a read-only
+    /// instance assigned the same device.
     pub fn script_apply(&mut self, script: &Script) -> Result<(), FdiskError> {
         log::debug!("Fdisk::script_apply applying script");
 
@@ -1910,31 +3668,23 @@ pub fn script_apply(&mut self, script: &Script) -> Result<(), FdiskError> {
         }
     }
 
-    // FIXME a script retains a pointer to the context it was created from
-    // https://github.com/util-linux/util-linux/blob/8aa25617467a1249669cff7240ca31973bf9a127/libfdisk/src/script.c#L103
-    // a new assignment here does not update this reference. Furthermore, calling this function
-    // can create a shared ownership of the same script between `fdisk_context` structures. How does this impact Rust ownership
-    // constraints?
-    //
-    // - Each value in Rust has an owner.
-    // - There can only be one owner at a time.
-    // - When the owner goes out of scope, the value will be dropped.
-
-    /// Associates a [`Script`] to this `Fdisk`, then applies it to create the in-memory partition
-    /// table it specifies.
+    /// Applies only the headers of the provided [`Script`] to this `Fdisk`: creates the
+    /// in-memory partition table of the type given by the `label` header, and sets its identifier
+    /// when a `label-id` header is present. Partition entries in the script are ignored.
+    ///
+    /// This `Fdisk` keeps a reference to `script` as its associated script (see
+    /// [`Fdisk::script`]) until [`Fdisk::script_dissociate`] is called.
+    ///
+    /// To write the changes to disk call [`Fdisk::partition_table_write_to_disk`].
     ///
-    /// FIXME as is, this function is unusable since there is currently no way to pass it a fully
-    /// owned [`Script`]. More information from developers upstream is needed for me to come up
-    /// with a better API design. To be clear, it is **MY** lack of knowledge about the code produced
-    /// upstream that is at fault here, **NOT** the developers of `libfdisk`. In the meantime... ¯\\\_(ツ)\_/¯
-    pub fn script_apply_headers(&mut self, mut script: Script) -> Result<(), FdiskError> {
+    /// **Note:** a [`Script`] borrows the `Fdisk` it was created from. To apply a script read
+    /// with [`Script::new_from_file`], create it from a separate `Fdisk`, for example a read-only
+    /// instance assigned the same device.
+    pub fn script_apply_headers(&mut self, script: &Script) -> Result<(), FdiskError> {
         log::debug!("Fdisk::script_apply_headers applying script headers");
 
-        // We are virtually ceding ownership of this instance to the C-side of the library;
-        // instance that will be automatically deallocated once it is out of scope, incrementing
-        // its reference counter protects it from being freed prematurely.
-        script.incr_ref_counter();
-
+        // `fdisk_apply_script_headers` calls `fdisk_set_script`, which takes its own reference to
+        // `script.inner`; the reference held by `script` is released when it is dropped.
         let result = unsafe { libfdisk::fdisk_apply_script_headers(self.inner, script.inner) };
 
         match result {
@@ -1976,6 +3726,21 @@ fn set_script(ptr: &mut Self, script: *mut libfdisk::fdisk_script) -> Result<(),
         }
     }
 
+    /// Associates a [`Script`] with this `Fdisk`, replacing the current one if any.
+    ///
+    /// This `Fdisk` keeps its own reference to `script`, which stays alive until it is
+    /// dissociated (see [`Fdisk::script_dissociate`]) even if `script` is dropped. The associated
+    /// script is available through [`Fdisk::script`] and [`Fdisk::script_mut`]; `libfdisk` uses
+    /// it, for example, to provide default answers to prompts.
+    ///
+    /// **Note:** a [`Script`] borrows the `Fdisk` it was created from, so `script` must come from
+    /// a separate `Fdisk`.
+    pub fn script_associate(&mut self, script: &Script) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::script_associate associating script");
+
+        Self::set_script(self, script.inner)
+    }
+
     /// Removes the [`Script`] currently associated with this `Fdisk`.
     pub fn script_dissociate(&mut self) -> Result<(), FdiskError> {
         log::debug!("Fdisk::script_dissociate dissociating script");
@@ -1983,6 +3748,129 @@ pub fn script_dissociate(&mut self) -> Result<(), FdiskError> {
         Self::set_script(self, std::ptr::null_mut())
     }
 
+    #[doc(hidden)]
+    /// Forwards a `libfdisk` prompt to the function set by [`Fdisk::set_prompt_callback`].
+    unsafe extern "C" fn prompt_trampoline(
+        _: *mut libfdisk::fdisk_context,
+        mut ask: *mut libfdisk::fdisk_ask,
+        data: *mut libc::c_void,
+    ) -> libc::c_int {
+        // `Prompt` is a transparent wrapper around a `libfdisk::fdisk_ask` pointer.
+        let prompt = &mut *(&mut ask as *mut _ as *mut Prompt);
+        let callback = &mut *(data as *mut PromptCallback);
+
+        // Unwinding across an `extern "C"` boundary aborts the process, catch panics here.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(prompt)));
+
+        match result {
+            Ok(Ok(_)) => 0,
+            Ok(Err(e)) => {
+                log::debug!("Fdisk::prompt_trampoline prompt callback failed. {}", e);
+
+                -libc::EINVAL
+            }
+            Err(_) => {
+                log::debug!("Fdisk::prompt_trampoline prompt callback panicked");
+
+                -libc::EINVAL
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Reinstalls the function set by [`Fdisk::set_prompt_callback`] (or removes any callback
+    /// if none was set), after a method temporarily replaced it with its own.
+    pub(crate) fn restore_prompt_callback(ptr: &Self) {
+        match ptr.prompt_callback {
+            Some(callback) => unsafe {
+                libfdisk::fdisk_set_ask(
+                    ptr.inner,
+                    Some(Self::prompt_trampoline),
+                    callback as *mut libc::c_void,
+                );
+            },
+            None => unsafe {
+                libfdisk::fdisk_set_ask(ptr.inner, None, std::ptr::null_mut());
+            },
+        }
+    }
+
+    #[doc(hidden)]
+    /// Deallocates the function set by [`Fdisk::set_prompt_callback`], if any.
+    fn drop_prompt_callback(ptr: &mut Self) {
+        if let Some(callback) = ptr.prompt_callback.take() {
+            let _ = unsafe { Box::from_raw(callback) };
+        }
+    }
+
+    /// Sets the function `libfdisk` calls to display information, or get answers from the user
+    /// (e.g. when adding a partition interactively, or by calling [`Fdisk::ask_yes_no_question`]).
+    ///
+    /// `callback` receives each [`Prompt`]; it can check the prompt's
+    /// [`kind`](Prompt::kind), and provide an answer with one of the `Prompt::*_set_answer`
+    /// methods. An error returned by `callback`, or a panic in `callback`, makes the operation
+    /// that emitted the prompt fail.
+    ///
+    /// Any previously set callback is replaced.
+    pub fn set_prompt_callback<F>(&mut self, callback: F) -> Result<(), FdiskError>
+    where
+        F: FnMut(&mut Prompt) -> Result<(), FdiskError> + 'static,
+    {
+        log::debug!("Fdisk::set_prompt_callback setting prompt callback");
+
+        let callback: PromptCallback = Box::new(callback);
+        let user_data = Box::into_raw(Box::new(callback));
+
+        let result = unsafe {
+            libfdisk::fdisk_set_ask(
+                self.inner,
+                Some(Self::prompt_trampoline),
+                user_data as *mut libc::c_void,
+            )
+        };
+
+        match result {
+            0 => {
+                log::debug!("Fdisk::set_prompt_callback set prompt callback");
+
+                Self::drop_prompt_callback(self);
+                self.prompt_callback = Some(user_data);
+
+                Ok(())
+            }
+            code => {
+                let _ = unsafe { Box::from_raw(user_data) };
+
+                let err_msg = "failed to set prompt callback".to_owned();
+                log::debug!("Fdisk::set_prompt_callback {}. libfdisk::fdisk_set_ask returned error code: {:?}", err_msg, code);
+
+                Err(FdiskError::Config(err_msg))
+            }
+        }
+    }
+
+    /// Removes the function set by [`Fdisk::set_prompt_callback`].
+    pub fn unset_prompt_callback(&mut self) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::unset_prompt_callback removing prompt callback");
+
+        let result = unsafe { libfdisk::fdisk_set_ask(self.inner, None, std::ptr::null_mut()) };
+
+        match result {
+            0 => {
+                log::debug!("Fdisk::unset_prompt_callback removed prompt callback");
+                Self::drop_prompt_callback(self);
+
+                Ok(())
+            }
+            code => {
+                let err_msg = "failed to remove prompt callback".to_owned();
+                log::debug!("Fdisk::unset_prompt_callback {}. libfdisk::fdisk_set_ask returned error code: {:?}", err_msg, code);
+
+                Err(FdiskError::Config(err_msg))
+            }
+        }
+    }
+
     //---- END mutators
 
     //---- BEGIN getters
@@ -2117,6 +4005,48 @@ pub fn device_model(&self) -> Option<&str> {
         }
     }
 
+    /// Returns the path to the kernel device node of the partition with the given identification
+    /// number (e.g. `/dev/sda2`, `/dev/nvme0n1p2`, `/dev/loop0p1`, etc.), or `None` if the
+    /// assigned device is an image file.
+    ///
+    /// Partition identification numbers start at `0`, while the kernel numbers partitions from
+    /// `1`; i.e. partition `0` on `/dev/sda` is `/dev/sda1`.
+    ///
+    /// **Note:** this method does not check that the partition, or its device node, exist.
+    pub fn partition_device_path(&self, partition_number: usize) -> Option<PathBuf> {
+        log::debug!(
+            "Fdisk::partition_device_path getting device path of partition {:?}",
+            partition_number
+        );
+
+        if self.device_is_image_file() {
+            log::debug!("Fdisk::partition_device_path assigned device is an image file");
+
+            return None;
+        }
+
+        let path = self.partition_name(partition_number).map(PathBuf::from);
+        log::debug!("Fdisk::partition_device_path value: {:?}", path);
+
+        path
+    }
+
+    /// Returns the name of the partition with the given identification number, built from the
+    /// assigned device's name (see [`misc::partition_name`]).
+    ///
+    /// Partition identification numbers start at `0`, while partition names are numbered from
+    /// `1`; i.e. partition `0` on `/dev/sda` is named `/dev/sda1`.
+    pub fn partition_name(&self, partition_number: usize) -> Option<String> {
+        log::debug!(
+            "Fdisk::partition_name getting name of partition {:?}",
+            partition_number
+        );
+
+        let device_name = self.device_name()?;
+
+        misc::partition_name(device_name, partition_number + 1)
+    }
+
     /// Returns the assigned device's name.
     pub fn device_name(&self) -> Option<&Path> {
         log::debug!("Fdisk::device_name getting assigned device's name");
@@ -2179,6 +4109,30 @@ pub fn device_last_lba(&self) -> u64 {
         last_lba
     }
 
+    /// Returns the LBA at which a brand-new first partition would start, given the current
+    /// partition table and the assigned device's alignment settings. Nothing is created on the
+    /// device.
+    ///
+    /// The value is computed by aligning the first usable LBA (see [`Fdisk::device_first_lba`])
+    /// up to the next grain boundary (see [`Fdisk::device_grain_size`]), within the range of
+    /// usable addresses. With the default 1 MiB grain on a device with 512-byte sectors, this is
+    /// LBA `2048` for both `GPT` and `DOS` partition tables, unless `DOS` compatibility mode is
+    /// active (in which case it is usually LBA `63`).
+    ///
+    /// This is the amount of space to reserve before the first partition, for example, for a
+    /// bootloader.
+    pub fn default_first_partition_lba(&self) -> u64 {
+        let first_lba = self.device_first_lba();
+        let last_lba = self.device_last_lba();
+
+        let address = unsafe {
+            libfdisk::fdisk_align_lba_in_range(self.inner, first_lba, first_lba, last_lba)
+        };
+        log::debug!("Fdisk::default_first_partition_lba value: {:?}", address);
+
+        address
+    }
+
     /// Returns the number of cylinder subdivisions of the assigned device.
     pub fn device_count_cylinders(&self) -> u64 {
         let cylinders = unsafe { libfdisk::fdisk_get_geom_cylinders(self.inner) };
@@ -2232,8 +4186,21 @@ pub fn device_minimum_io_size(&self) -> u64 {
 
     /// Returns the preferred optimal number of bytes for streaming Input/Output on the assigned
     /// device.
+    ///
+    /// Some devices (e.g. image files) do not report an optimal I/O size. In that case, like
+    /// `util-linux`, this method falls back to, in order:
+    /// - the device's minimum I/O size (see [`Fdisk::device_minimum_io_size`]),
+    /// - the device's physical sector size (see [`Fdisk::device_bytes_per_physical_sector`]),
+    ///
+    /// so that the returned value is never `0`.
     pub fn device_optimal_io_size(&self) -> u64 {
-        let opt_io_size = unsafe { libfdisk::fdisk_get_minimal_iosize(self.inner) };
+        let opt_io_size = match unsafe { libfdisk::fdisk_get_optimal_iosize(self.inner) } {
+            0 => match self.device_minimum_io_size() {
+                0 => self.device_bytes_per_physical_sector(),
+                min_io_size => min_io_size,
+            },
+            opt_io_size => opt_io_size,
+        };
         log::debug!(
             "Fdisk::device_optimal_io_size optimal I/O size: {:?}",
             opt_io_size
@@ -2250,26 +4217,70 @@ pub fn device_size_in_bytes(&self) -> u64 {
         size
     }
 
-    /// Returns the size of the assigned device in logical sectors.
-    pub fn device_size_in_sectors(&self) -> u64 {
-        let size = unsafe { libfdisk::fdisk_get_nsectors(self.inner) };
-        log::debug!("Fdisk::device_size_in_sectors size (sectors): {:?}", size);
+    /// Returns the number of bytes actually allocated on disk for the assigned image file, or
+    /// `None` if the assigned device is a block device.
+    ///
+    /// For a sparse image file, this value can be much smaller than its apparent size (see
+    /// [`Fdisk::device_size_in_bytes`]), since unwritten regions take no space on disk.
+    pub fn image_file_allocated_size(&self) -> Result<Option<u64>, FdiskError> {
+        log::debug!("Fdisk::image_file_allocated_size getting allocated size of image file");
 
-        size
-    }
+        if !self.device_is_image_file() {
+            log::debug!("Fdisk::image_file_allocated_size assigned device is not an image file");
 
-    /// Returns the size in bytes of a logical sector.
-    pub fn device_bytes_per_logical_sector(&self) -> u64 {
-        let size = unsafe { libfdisk::fdisk_get_sector_size(self.inner) };
-        log::debug!(
-            "Fdisk::device_bytes_per_logical_sector bytes per sector: {:?}",
-            size
-        );
+            return Ok(None);
+        }
 
-        size
-    }
+        let fd = unsafe { libfdisk::fdisk_get_devfd(self.inner) };
+        let mut stat = MaybeUninit::<libc::stat>::zeroed();
 
-    /// Returns the size in bytes of a physical sector.
+        let result = unsafe { libc::fstat(fd, stat.as_mut_ptr()) };
+
+        match result {
+            0 => {
+                let stat = unsafe { stat.assume_init() };
+                // `st_blocks` is always expressed in 512-byte units, whatever the file system's
+                // block size.
+                let size = stat.st_blocks as u64 * 512;
+                log::debug!(
+                    "Fdisk::image_file_allocated_size allocated size (bytes): {:?}",
+                    size
+                );
+
+                Ok(Some(size))
+            }
+            _ => {
+                let err_msg = format!(
+                    "failed to get status of image file: {}",
+                    std::io::Error::last_os_error()
+                );
+                log::debug!("Fdisk::image_file_allocated_size {}", err_msg);
+
+                Err(FdiskError::IoError(err_msg))
+            }
+        }
+    }
+
+    /// Returns the size of the assigned device in logical sectors.
+    pub fn device_size_in_sectors(&self) -> u64 {
+        let size = unsafe { libfdisk::fdisk_get_nsectors(self.inner) };
+        log::debug!("Fdisk::device_size_in_sectors size (sectors): {:?}", size);
+
+        size
+    }
+
+    /// Returns the size in bytes of a logical sector.
+    pub fn device_bytes_per_logical_sector(&self) -> u64 {
+        let size = unsafe { libfdisk::fdisk_get_sector_size(self.inner) };
+        log::debug!(
+            "Fdisk::device_bytes_per_logical_sector bytes per sector: {:?}",
+            size
+        );
+
+        size
+    }
+
+    /// Returns the size in bytes of a physical sector.
     pub fn device_bytes_per_physical_sector(&self) -> u64 {
         let size = unsafe { libfdisk::fdisk_get_physector_size(self.inner) };
         log::debug!(
@@ -2518,8 +4529,8 @@ pub fn partition_size_format(&self) -> SizeFormat {
         size_format
     }
 
-    /// Returns the assigned device's name.
-    /// Returns the content of a [`Partition`]'s field in string form.
+    /// Returns the content of a [`Partition`]'s field in string form, formatted the way `fdisk -l`
+    /// displays it (e.g. sizes follow [`Fdisk::partition_size_format`]).
     pub fn partition_field_to_string(
         &self,
         field: Field,
@@ -2546,6 +4557,12 @@ pub fn partition_field_to_string(
             0 => {
                 let ptr = unsafe { content_ptr.assume_init() };
                 let field_content = ffi_to_string_or_empty!(ptr);
+
+                // Freeing `libfdisk`-allocated string.
+                unsafe {
+                    libc::free(ptr as *mut _);
+                }
+
                 log::debug!("Fdisk::partition_field_to_string converted content of partition field {:?} to {:?}", field, field_content);
 
                 Ok(field_content)
@@ -2562,6 +4579,31 @@ pub fn partition_field_to_string(
         }
     }
 
+    /// Returns the content of the requested `fields` of the partition with the given
+    /// identification number, in string form and in the same order as `fields`.
+    ///
+    /// The partition is looked up once, then each field is converted with
+    /// [`Fdisk::partition_field_to_string`], which makes this method convenient to build one row
+    /// of a partition listing.
+    pub fn partition_row(
+        &self,
+        partition_number: usize,
+        fields: &[Field],
+    ) -> Result<Vec<String>, FdiskError> {
+        log::debug!(
+            "Fdisk::partition_row converting fields {:?} of partition {:?} to string",
+            fields,
+            partition_number
+        );
+
+        let partition = self.get_partition(partition_number)?;
+
+        fields
+            .iter()
+            .map(|&field| self.partition_field_to_string(field, &partition))
+            .collect()
+    }
+
     #[doc(hidden)]
     /// Gets a partition by its index number in a partition array.
     fn get_partition_by_number(
@@ -2619,9 +4661,134 @@ pub fn partition_by_number_mut(&mut self, partition_number: usize) -> Option<&mu
             .map(|ptr| owning_mut_from_ptr!(self, Partition, ptr))
     }
 
+    #[doc(hidden)]
+    /// Gets a partition by its identification number, or returns an error if its slot in the
+    /// partition table is unused.
+    fn existing_partition(fdisk: &Self, partition_number: usize) -> Result<Partition, FdiskError> {
+        if !fdisk.partition_is_number_in_use(partition_number) {
+            let err_msg = format!(
+                "no partition with identification number: {:?}",
+                partition_number
+            );
+            log::debug!("Fdisk::existing_partition {}", err_msg);
+
+            return Err(FdiskError::NoSuchPartition(err_msg));
+        }
+
+        fdisk.get_partition(partition_number)
+    }
+
+    /// Returns a copy of the partition with the given identification number, as described in the
+    /// partition table on the assigned device.
+    ///
+    /// The returned [`Partition`] holds all the information available for the entry (starting
+    /// sector, size, type, name, UUID, etc.). Unused entries in the partition table are returned
+    /// too, without a starting sector or size; since they are table slots rather than unallocated
+    /// regions, their [`Partition::is_free_space`] method returns `false`.
+    ///
+    /// Returns a [`FdiskError::NoSuchPartition`] error if `partition_number` is out of the range
+    /// of entries supported by the partition table, or if the device has no partition table.
+    pub fn get_partition(&self, partition_number: usize) -> Result<Partition, FdiskError> {
+        log::debug!(
+            "Fdisk::get_partition getting partition with identification number: {:?}",
+            partition_number
+        );
+
+        let mut partition_ptr = MaybeUninit::<*mut libfdisk::fdisk_partition>::zeroed();
+
+        let result = unsafe {
+            libfdisk::fdisk_get_partition(self.inner, partition_number, partition_ptr.as_mut_ptr())
+        };
+
+        match result {
+            0 => {
+                log::debug!(
+                    "Fdisk::get_partition got partition with identification number: {:?}",
+                    partition_number
+                );
+
+                let ptr = unsafe { partition_ptr.assume_init() };
+                let partition = Partition::from_ptr(ptr);
+
+                Ok(partition)
+            }
+            code => {
+                let err_msg = format!(
+                    "failed to get partition with identification number: {:?}",
+                    partition_number
+                );
+                log::debug!("Fdisk::get_partition {}. libfdisk::fdisk_get_partition returned error code: {:?}", err_msg, code);
+
+                match -code {
+                    libc::EINVAL => {
+                        let err_msg = format!(
+                            "no partition with identification number: {:?}",
+                            partition_number
+                        );
+
+                        Err(FdiskError::NoSuchPartition(err_msg))
+                    }
+                    libc::ENOMEM => Err(FdiskError::OutOfMemory(err_msg)),
+                    _ => Err(FdiskError::Unexpected(err_msg)),
+                }
+            }
+        }
+    }
+
+    /// Returns the size in bytes of the partition with the given identification number, or
+    /// `None` if the partition's size is not set.
+    ///
+    /// The partition's size in sectors is multiplied by the device's logical sector size (see
+    /// [`Fdisk::device_bytes_per_logical_sector`]).
+    pub fn partition_size_in_bytes(
+        &self,
+        partition_number: usize,
+    ) -> Result<Option<u64>, FdiskError> {
+        log::debug!(
+            "Fdisk::partition_size_in_bytes getting size of partition with identification number: {:?}",
+            partition_number
+        );
+
+        let partition = Self::existing_partition(self, partition_number)?;
+        let sector_size = self.device_bytes_per_logical_sector();
+        let size = partition.size_in_sectors().map(|size| size * sector_size);
+        log::debug!("Fdisk::partition_size_in_bytes value: {:?}", size);
+
+        Ok(size)
+    }
+
+    /// Returns the half-open range of byte offsets `[start, end)` occupied by the partition with
+    /// the given identification number, or `None` if either of its starting or ending sector is
+    /// not set.
+    ///
+    /// Offsets are relative to the beginning of the assigned device.
+    pub fn partition_bytes_range(
+        &self,
+        partition_number: usize,
+    ) -> Result<Option<std::ops::Range<u64>>, FdiskError> {
+        log::debug!(
+            "Fdisk::partition_bytes_range getting byte range of partition with identification number: {:?}",
+            partition_number
+        );
+
+        let partition = Self::existing_partition(self, partition_number)?;
+        let sector_size = self.device_bytes_per_logical_sector();
+        let range = partition
+            .starting_sector()
+            .zip(partition.ending_sector())
+            .map(|(start, end)| (start * sector_size)..((end + 1) * sector_size));
+        log::debug!("Fdisk::partition_bytes_range value: {:?}", range);
+
+        Ok(range)
+    }
+
     /// Returns a list of unallocated spaces on the assigned device as a collection of
     /// [`Partition`]s, or `None` if the device has no partition table.
     ///
+    /// The entries in the list are synthesized by `libfdisk`, they do not exist in the partition
+    /// table. Each one has its starting sector and size set, [`Partition::is_free_space`] returns
+    /// `true`, and [`Partition::number`] returns `None`.
+    ///
     /// **Note:** this method will ignore free space smaller than the assigned device's grain
     /// size (see [`Fdisk::device_grain_size`])
     pub fn list_empty_spaces(&self) -> Option<PartitionList> {
@@ -2679,17 +4846,191 @@ pub fn list_partitions(&self) -> Option<PartitionList> {
         }
     }
 
+    #[doc(hidden)]
+    /// Removes from `list` every [`Partition`] for which `keep` returns `false`.
+    fn retain_partitions<F>(list: &mut PartitionList, keep: F)
+    where
+        F: Fn(&Partition) -> bool,
+    {
+        for index in (0..list.len()).rev() {
+            if !list.get(index).map(&keep).unwrap_or(false) {
+                let _ = list.remove(index);
+            }
+        }
+    }
+
+    /// Returns a list of the [`Partition`]s in this `Fdisk` pointing to a used area on disk,
+    /// excluding free space placeholders (see [`Partition::points_to_used_area`]).
+    pub fn used_partitions(&self) -> Result<PartitionList, FdiskError> {
+        log::debug!("Fdisk::used_partitions listing partitions pointing to used areas");
+
+        let mut list = self.list_partitions().ok_or_else(|| {
+            let err_msg = "failed to list partitions in partition table".to_owned();
+            log::debug!("Fdisk::used_partitions {}", err_msg);
+
+            FdiskError::Config(err_msg)
+        })?;
+
+        Self::retain_partitions(&mut list, Partition::points_to_used_area);
+        log::debug!(
+            "Fdisk::used_partitions listed {:?} partitions pointing to used areas",
+            list.len()
+        );
+
+        Ok(list)
+    }
+
+    /// Returns, for each partition pointing to a used area on disk, a tuple with its
+    /// identification number, and `true` if its first sector is aligned to a physical sector
+    /// boundary (see [`Fdisk::is_lba_physically_aligned`]).
+    ///
+    /// Misaligned partitions on devices with 4096-byte physical sectors suffer from
+    /// read-modify-write cycles, and degraded performance.
+    pub fn partition_alignment_report(&self) -> Result<Vec<(usize, bool)>, FdiskError> {
+        log::debug!("Fdisk::partition_alignment_report checking partition alignment");
+
+        let list = self.used_partitions()?;
+        let report: Vec<(usize, bool)> = list
+            .iter()
+            .filter_map(
+                |partition| match (partition.number(), partition.starting_sector()) {
+                    (Some(number), Some(start)) => {
+                        Some((number, self.is_lba_physically_aligned(start)))
+                    }
+                    _ => None,
+                },
+            )
+            .collect();
+
+        log::debug!(
+            "Fdisk::partition_alignment_report alignment report: {:?}",
+            report
+        );
+
+        Ok(report)
+    }
+
+    /// Returns the identification numbers of each pair of partitions sharing sectors on the
+    /// assigned device.
+    ///
+    /// `libfdisk` reads partition tables with overlapping partitions (e.g. corrupted, or
+    /// hand-edited tables) without complaint, although writing to any of the overlapping
+    /// partitions will damage the others. This method does not modify the partition table.
+    ///
+    /// Containers (e.g. `DOS` extended partitions) are not checked against the partitions they
+    /// hold (see [`PartitionList::overlaps`]).
+    pub fn detect_overlaps(&self) -> Result<Vec<(usize, usize)>, FdiskError> {
+        log::debug!("Fdisk::detect_overlaps looking for overlapping partitions");
+
+        let partitions = self.used_partitions()?;
+
+        let overlaps: Vec<(usize, usize)> = partitions
+            .overlaps()
+            .into_iter()
+            .filter_map(|problem| match problem {
+                LayoutProblem::Overlap {
+                    first: Some(first),
+                    second: Some(second),
+                    ..
+                } => Some((first, second)),
+                _ => None,
+            })
+            .collect();
+        log::debug!("Fdisk::detect_overlaps found overlaps: {:?}", overlaps);
+
+        Ok(overlaps)
+    }
+
+    /// Returns the EFI System Partition (ESP) on the assigned device, or `None` if there is
+    /// none.
+    ///
+    /// The ESP is the first partition of type [`Guid::EfiSystem`] on a `GPT`, or of type
+    /// [`Code::EfiSystem`] (`0xef`) on an `MBR` partition table.
+    pub fn find_efi_system_partition(&self) -> Result<Option<Partition>, FdiskError> {
+        log::debug!("Fdisk::find_efi_system_partition looking for EFI System Partition");
+
+        let partitions = self.used_partitions()?;
+
+        let esp_number = partitions
+            .iter()
+            .find(|partition| {
+                partition
+                    .partition_type()
+                    .map(|kind| {
+                        kind.guid().and_then(|guid| guid.parse::<Guid>().ok())
+                            == Some(Guid::EfiSystem)
+                            || kind.code() == Some(Code::EfiSystem.to_u32())
+                    })
+                    .unwrap_or(false)
+            })
+            .and_then(|partition| partition.number());
+
+        match esp_number {
+            Some(partition_number) => {
+                log::debug!(
+                    "Fdisk::find_efi_system_partition found EFI System Partition: {:?}",
+                    partition_number
+                );
+
+                Self::existing_partition(self, partition_number).map(Some)
+            }
+            None => {
+                log::debug!("Fdisk::find_efi_system_partition no EFI System Partition found");
+
+                Ok(None)
+            }
+        }
+    }
+
     /// Returns the content of an entry in the partition table header.
-    pub fn partition_table_header_entry(
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FdiskError::Config`] error if the device has no partition table, if
+    /// `header_entry` belongs to another type of partition table (e.g.
+    /// [`HeaderEntry::GptDiskGuid`] on a `DOS` partition table), or if `libfdisk` fails to read
+    /// the entry.
+    pub fn partition_table_item(
         &self,
         header_entry: HeaderEntry,
-    ) -> Option<HeaderEntryContent> {
+    ) -> Result<HeaderEntryContent, FdiskError> {
         log::debug!(
-            "Fdisk::partition_table_header_entry getting content of partition table header entry {:?}",
+            "Fdisk::partition_table_item getting content of partition table header entry {:?}",
             header_entry
         );
+
+        if !self.device_has_partition_table() {
+            let err_msg = format!(
+                "failed to get content of partition table header entry {:?}. No partition table on device",
+                header_entry
+            );
+            log::debug!("Fdisk::partition_table_item {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        if let Some(kind) = header_entry.partition_table_kind() {
+            if !self.partition_table_is_of_type(kind) {
+                let err_msg = format!(
+                    "header entry {:?} does not belong to the device's partition table",
+                    header_entry
+                );
+                log::debug!("Fdisk::partition_table_item {}", err_msg);
+
+                return Err(FdiskError::Config(err_msg));
+            }
+        }
+
         let c_header_entry = header_entry.to_original_u32() as i32;
-        let content = HeaderEntryContent::new().ok()?;
+        let content = HeaderEntryContent::new().map_err(|e| {
+            let err_msg = format!(
+                "failed to get content of partition table header entry {:?}. {}",
+                header_entry, e
+            );
+            log::debug!("Fdisk::partition_table_item {}", err_msg);
+
+            FdiskError::OutOfMemory(err_msg)
+        })?;
 
         let result = unsafe {
             libfdisk::fdisk_get_disklabel_item(self.inner, c_header_entry, content.inner)
@@ -2698,24 +5039,45 @@ pub fn partition_table_header_entry(
         match result {
             0 => {
                 log::debug!(
-                    "Partition::partition_table_header_entry got content of partition table header entry {:?}",
+                    "Fdisk::partition_table_item got content of partition table header entry {:?}",
+                    header_entry
+                );
+
+                Ok(content)
+            }
+            1 => {
+                let err_msg = format!(
+                    "header entry {:?} is not supported by the device's partition table",
                     header_entry
                 );
+                log::debug!(
+                    "Fdisk::partition_table_item {}. libfdisk::fdisk_get_disklabel_item returned 1",
+                    err_msg
+                );
 
-                Some(content)
+                Err(FdiskError::Config(err_msg))
             }
             code => {
                 let err_msg = format!(
                     "failed to get content of partition table header entry {:?}",
                     header_entry
                 );
-                log::debug!("Fdisk::partition_table_header_entry {}. libfdisk::fdisk_get_disklabel_item returned error code: {:?}", err_msg, code);
+                log::debug!("Fdisk::partition_table_item {}. libfdisk::fdisk_get_disklabel_item returned error code: {:?}", err_msg, code);
 
-                None
+                Err(FdiskError::Config(err_msg))
             }
         }
     }
 
+    /// Returns the content of an entry in the partition table header, or `None` if it can not
+    /// be read (see [`Fdisk::partition_table_item`] for details).
+    pub fn partition_table_header_entry(
+        &self,
+        header_entry: HeaderEntry,
+    ) -> Option<HeaderEntryContent> {
+        self.partition_table_item(header_entry).ok()
+    }
+
     /// Returns the partition table's ID in string form.
     pub fn partition_table_id(&self) -> Option<String> {
         log::debug!("Fdisk::partition_table_id getting partition table's ID");
@@ -2759,45 +5121,335 @@ pub fn partition_table_id(&self) -> Option<String> {
         }
     }
 
-    #[doc(hidden)]
-    /// Returns the partition table associated with this `Fdisk`.
-    fn get_partition_table(
-        ptr: &Self,
-        name: *const libc::c_char,
-    ) -> Option<*mut libfdisk::fdisk_label> {
-        let mut part_table_ptr = MaybeUninit::<*mut libfdisk::fdisk_label>::zeroed();
-        unsafe {
-            part_table_ptr.write(libfdisk::fdisk_get_label(ptr.inner, name));
+    /// Returns a machine-readable summary of the partition table on the assigned device.
+    ///
+    /// Returns an error if the device has no partition table.
+    pub fn summary(&self) -> Result<PartitionTableSummary, FdiskError> {
+        log::debug!("Fdisk::summary summarizing partition table");
+
+        let kind = self
+            .partition_table_current()
+            .map(|table| table.kind())
+            .ok_or_else(|| {
+                let err_msg = "device has no partition table".to_owned();
+                log::debug!("Fdisk::summary {}", err_msg);
+
+                FdiskError::Config(err_msg)
+            })?;
+
+        let partition_count = self
+            .list_partitions()
+            .map(|partitions| partitions.len())
+            .unwrap_or(0);
+
+        let summary = PartitionTableSummary::new(
+            kind,
+            self.partition_table_id(),
+            self.device_bytes_per_logical_sector(),
+            self.device_bytes_per_physical_sector(),
+            self.device_first_lba(),
+            self.device_last_lba(),
+            self.partition_table_max_partitions(),
+            partition_count,
+        );
+        log::debug!("Fdisk::summary value: {:?}", summary);
+
+        Ok(summary)
+    }
+
+    /// Exports the partition table on the assigned device, and its partitions, as a portable
+    /// [`DiskLayout`] which can be re-imported with [`Fdisk::import_layout`].
+    ///
+    /// Only partitions pointing to a used area on disk are exported (see
+    /// [`Fdisk::used_partitions`]), in increasing order of partition number.
+    ///
+    /// Returns an error if the device has no partition table.
+    pub fn export_layout(&self) -> Result<DiskLayout, FdiskError> {
+        log::debug!("Fdisk::export_layout exporting disk layout");
+
+        let summary = self.summary()?;
+
+        let mut partitions: Vec<PartitionSpec> = self
+            .used_partitions()?
+            .iter()
+            .filter_map(|partition| {
+                let number = partition.number()?;
+                let starting_sector = partition.starting_sector()?;
+                let size_in_sectors = partition.size_in_sectors()?;
+
+                let partition_type = partition.partition_type().and_then(|kind| {
+                    kind.guid()
+                        .map(str::to_owned)
+                        .or_else(|| kind.code().map(|code| format!("{:x}", code)))
+                });
+                let attributes = partition
+                    .attribute_bits()
+                    .map(|bits| String::from_utf8_lossy(&bits).into_owned())
+                    .filter(|bits| !bits.is_empty());
+
+                let spec = PartitionSpec::builder()
+                    .number(number)
+                    .starting_sector(starting_sector)
+                    .size_in_sectors(size_in_sectors)
+                    .partition_type_opt(partition_type)
+                    .name_opt(partition.name().map(str::to_owned))
+                    .uuid_opt(partition.uuid().map(str::to_owned))
+                    .attributes_opt(attributes)
+                    .build();
+
+                Some(spec)
+            })
+            .collect();
+        partitions.sort_by_key(|spec| spec.number());
+
+        let layout = DiskLayout::new(
+            summary.kind(),
+            summary.id().map(str::to_owned),
+            summary.bytes_per_logical_sector(),
+            partitions,
+        );
+        log::debug!("Fdisk::export_layout value: {:?}", layout);
+
+        Ok(layout)
+    }
+
+    /// Replaces the partition table on the assigned device with a new, in-memory one matching
+    /// the given `layout` (see [`Fdisk::export_layout`]).
+    ///
+    /// Returns a [`FdiskError::Config`] error if the layout's sector size differs from the
+    /// assigned device's logical sector size, or if any of its partitions can not be added.
+    ///
+    /// The layout's partitions and identifier are converted and checked before the current
+    /// partition table is replaced, which leaves it untouched on failure. However, `libfdisk`
+    /// only detects some problems (e.g. overlapping partitions) while adding partitions; in that
+    /// case, the in-memory partition table only holds the partitions added before the failure.
+    ///
+    /// **Note:** changes are only written to disk after calling
+    /// [`Fdisk::partition_table_write_to_disk`].
+    pub fn import_layout(&mut self, layout: &DiskLayout) -> Result<(), FdiskError> {
+        log::debug!("Fdisk::import_layout importing disk layout: {:?}", layout);
+
+        let sector_size = self.device_bytes_per_logical_sector();
+        if layout.sector_size() != sector_size {
+            let err_msg = format!(
+                "layout sector size ({:?} bytes) differs from the device's logical sector size ({:?} bytes)",
+                layout.sector_size(),
+                sector_size
+            );
+            log::debug!("Fdisk::import_layout {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
         }
 
-        match unsafe { part_table_ptr.assume_init() } {
-            ptr if ptr.is_null() => {
-                log::debug!("Fdisk::get_partition_table found no partition table. libfdisk::fdisk_get_label returned a NULL pointer");
+        // Convert and check everything before replacing the current partition table.
+        let partitions = layout
+            .partitions()
+            .iter()
+            .map(|spec| Self::partition_from_spec(self, layout.label_kind(), spec))
+            .collect::<Result<Vec<_>, _>>()?;
 
-                None
-            }
-            ptr => {
-                log::debug!("Fdisk::get_partition_table found requested partition table");
+        if let Some(id) = layout.disk_id() {
+            if !Self::is_valid_partition_table_id(layout.label_kind(), id) {
+                let err_msg = format!(
+                    "invalid identifier for a {:?} partition table: {:?}",
+                    layout.label_kind(),
+                    id
+                );
+                log::debug!("Fdisk::import_layout {}", err_msg);
 
-                Some(ptr)
+                return Err(FdiskError::Config(err_msg));
             }
         }
-    }
 
-    /// Returns a reference to the current partition table associated with this `Fdisk`.
-    pub fn partition_table_current(&self) -> Option<&PartitionTable> {
-        log::debug!("Fdisk::partition_table_current getting current partition table");
+        self.partition_table_create(layout.label_kind())?;
 
-        Self::get_partition_table(self, std::ptr::null())
-            .map(|ptr| owning_ref_from_ptr!(self, PartitionTable, ptr))
-    }
+        if let Some(id) = layout.disk_id() {
+            self.partition_table_set_string_id(id)?;
+        }
 
-    /// Returns a mutable reference to the current partition table associated with this `Fdisk`.
-    pub fn partition_table_current_mut(&mut self) -> Option<&mut PartitionTable> {
-        log::debug!("Fdisk::partition_table_current_mut getting current partition table");
+        for partition in partitions {
+            self.partition_add(partition)?;
+        }
 
-        Self::get_partition_table(self, std::ptr::null())
-            .map(|ptr| owning_mut_from_ptr!(self, PartitionTable, ptr))
+        log::debug!(
+            "Fdisk::import_layout imported {:?} partitions",
+            layout.partitions().len()
+        );
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Converts a [`PartitionSpec`] into a [`Partition`] for a partition table of type `kind`.
+    fn partition_from_spec(
+        fdisk: &Self,
+        kind: PartitionTableKind,
+        spec: &PartitionSpec,
+    ) -> Result<Partition, FdiskError> {
+        let to_fdisk_error = |e: &dyn std::fmt::Display| {
+            let err_msg = format!(
+                "failed to create partition {:?} from layout. {}",
+                spec.number(),
+                e
+            );
+            log::debug!("Fdisk::partition_from_spec {}", err_msg);
+
+            FdiskError::Config(err_msg)
+        };
+
+        let mut partition = Partition::builder()
+            .number(spec.number())
+            .starting_sector(spec.starting_sector())
+            .size_in_sectors(spec.size_in_sectors())
+            .build()
+            .map_err(|e| to_fdisk_error(&e))?;
+
+        if let Some(id) = spec.partition_type() {
+            if let Some(table) = fdisk.partition_table_by_type(kind) {
+                let kind = table
+                    .partition_type_from_string_id(id)
+                    .map_err(|e| to_fdisk_error(&e))?;
+                partition
+                    .set_partition_type(kind)
+                    .map_err(|e| to_fdisk_error(&e))?;
+            }
+        }
+
+        if let Some(name) = spec.name() {
+            partition
+                .set_name(name.to_owned())
+                .map_err(|e| to_fdisk_error(&e))?;
+        }
+
+        if let Some(uuid) = spec.uuid() {
+            partition
+                .set_uuid(uuid.to_owned())
+                .map_err(|e| to_fdisk_error(&e))?;
+        }
+
+        if let Some(attributes) = spec.attributes() {
+            partition
+                .set_attribute_bits(attributes.as_bytes().to_vec())
+                .map_err(|e| to_fdisk_error(&e))?;
+        }
+
+        Ok(partition)
+    }
+
+    #[doc(hidden)]
+    /// Returns the partition table associated with this `Fdisk`.
+    fn get_partition_table(
+        ptr: &Self,
+        name: *const libc::c_char,
+    ) -> Option<*mut libfdisk::fdisk_label> {
+        let mut part_table_ptr = MaybeUninit::<*mut libfdisk::fdisk_label>::zeroed();
+        unsafe {
+            part_table_ptr.write(libfdisk::fdisk_get_label(ptr.inner, name));
+        }
+
+        match unsafe { part_table_ptr.assume_init() } {
+            ptr if ptr.is_null() => {
+                log::debug!("Fdisk::get_partition_table found no partition table. libfdisk::fdisk_get_label returned a NULL pointer");
+
+                None
+            }
+            ptr => {
+                log::debug!("Fdisk::get_partition_table found requested partition table");
+
+                Some(ptr)
+            }
+        }
+    }
+
+    /// Returns a reference to the current partition table associated with this `Fdisk`, i.e. the
+    /// one found on the assigned device or last created by [`Fdisk::partition_table_create`], or
+    /// `None` if there is no partition table.
+    ///
+    /// The returned reference borrows this `Fdisk`, so it can not outlive it.
+    pub fn partition_table_current(&self) -> Option<&PartitionTable> {
+        log::debug!("Fdisk::partition_table_current getting current partition table");
+
+        Self::get_partition_table(self, std::ptr::null())
+            .map(|ptr| owning_ref_from_ptr!(self, PartitionTable, ptr))
+    }
+
+    /// Returns a mutable reference to the current partition table associated with this `Fdisk`,
+    /// or `None` if there is no partition table (see [`Fdisk::partition_table_current`]).
+    pub fn partition_table_current_mut(&mut self) -> Option<&mut PartitionTable> {
+        log::debug!("Fdisk::partition_table_current_mut getting current partition table");
+
+        Self::get_partition_table(self, std::ptr::null())
+            .map(|ptr| owning_mut_from_ptr!(self, PartitionTable, ptr))
+    }
+
+    /// Returns `true` when the current partition table has in-memory modifications that have not
+    /// yet been written to the assigned device.
+    ///
+    /// Returns `false` if the device has no partition table.
+    pub fn has_unsaved_changes(&self) -> bool {
+        let state = self
+            .partition_table_current()
+            .map(|table| table.has_changes())
+            .unwrap_or(false);
+        log::debug!("Fdisk::has_unsaved_changes value: {:?}", state);
+
+        state
+    }
+
+    /// Expands a partition type shortcut (e.g. `L` for a Linux partition, `S` for a swap
+    /// partition, etc.), or alias (e.g. `linux`, `swap`, etc.), into the corresponding
+    /// [`PartitionKind`] of the current partition table.
+    ///
+    /// This is what interactive `fdisk` does when a user types a shortcut at the partition type
+    /// prompt.
+    ///
+    /// Returns an error if there is no partition table, if the partition table does not support
+    /// shortcuts (see [`PartitionTable::supports_partition_type_shortcuts`]), or if `input` is
+    /// not a known shortcut.
+    pub fn expand_type_shortcut(&self, input: &str) -> Result<PartitionKind, ParserError> {
+        log::debug!(
+            "Fdisk::expand_type_shortcut expanding shortcut: {:?}",
+            input
+        );
+
+        let table = self.partition_table_current().ok_or_else(|| {
+            let err_msg = "no partition table on device".to_owned();
+            log::debug!("Fdisk::expand_type_shortcut {}", err_msg);
+
+            ParserError::Shortcut(err_msg)
+        })?;
+
+        if !table.supports_partition_type_shortcuts() {
+            let err_msg = format!(
+                "{} partition tables do not support partition type shortcuts",
+                table.name().unwrap_or("unknown")
+            );
+            log::debug!("Fdisk::expand_type_shortcut {}", err_msg);
+
+            return Err(ParserError::Shortcut(err_msg));
+        }
+
+        let shortcut = (0..)
+            .map_while(|nth| table.partition_type_shortcut(nth))
+            .find(|shortcut| {
+                shortcut.shortcut().eq_ignore_ascii_case(input)
+                    || shortcut.alias().eq_ignore_ascii_case(input)
+            })
+            .ok_or_else(|| {
+                let err_msg = format!("unknown partition type shortcut: {:?}", input);
+                log::debug!("Fdisk::expand_type_shortcut {}", err_msg);
+
+                ParserError::Shortcut(err_msg)
+            })?;
+
+        table
+            .partition_type_from_string_id(shortcut.type_string())
+            .map_err(|e| {
+                let err_msg = format!("failed to expand shortcut {:?} {}", input, e);
+                log::debug!("Fdisk::expand_type_shortcut {}", err_msg);
+
+                ParserError::Shortcut(err_msg)
+            })
     }
 
     /// Returns a reference to the current table associated with this `Fdisk` matching the given type.
@@ -2838,11 +5490,64 @@ pub fn partition_table_count_types(&self) -> usize {
         count
     }
 
-    /// Returns the maximum number of partitions the partition table can hold.
+    /// Returns the types of partition table supported by this `Fdisk` that fit on the assigned
+    /// device.
+    ///
+    /// A partition table type is kept if the device is large enough to hold its on-disk
+    /// structures, plus at least one sector of usable space. The minimum sizes used, with `S`
+    /// the device's logical sector size, are:
+    /// - `BSD`: `8 KiB` of bootstrap area (which includes the disk label) + `S`,
+    /// - `DOS`: `2 * S` (MBR + one usable sector),
+    /// - `GPT`: `5 * S + 32 KiB` (protective MBR, primary and backup headers, primary and backup
+    ///   partition entry arrays of 128 entries of 128 bytes each, one usable sector),
+    /// - `SGI`: `2 * S` (volume header + one usable sector),
+    /// - `SUN`: `2 * S` (disk label + one usable sector).
+    ///
+    /// Disabled partition table types are excluded.
+    pub fn labels_fitting_device(&self) -> Vec<PartitionTableKind> {
+        log::debug!(
+            "Fdisk::labels_fitting_device listing partition table types fitting the device"
+        );
+
+        let sector_size = self.device_bytes_per_logical_sector();
+        let device_size = self.device_size_in_bytes();
+
+        let kinds: Vec<_> = [
+            (PartitionTableKind::BSD, 8_192 + sector_size),
+            (PartitionTableKind::DOS, 2 * sector_size),
+            (
+                PartitionTableKind::GPT,
+                4 * sector_size + 2 * 16_384 + sector_size,
+            ),
+            (PartitionTableKind::SGI, 2 * sector_size),
+            (PartitionTableKind::SUN, 2 * sector_size),
+        ]
+        .into_iter()
+        .filter(|(kind, min_size)| {
+            device_size >= *min_size
+                && self
+                    .partition_table_by_type(*kind)
+                    .map(|table| !table.is_disabled())
+                    .unwrap_or(false)
+        })
+        .map(|(kind, _)| kind)
+        .collect();
+
+        log::debug!("Fdisk::labels_fitting_device value: {:?}", kinds);
+
+        kinds
+    }
+
+    /// Returns the maximum number of partitions the partition table can hold, i.e. the number of
+    /// partition slots it supports (e.g. `128` for a default `GPT`, `16` for `SGI`, `8` for
+    /// `SUN`).
     ///
-    /// **Note:** this function will always return `4` for `MBR` partitions, value which
-    /// corresponds to the maximum number of logical partitions. Use an extended partition to lift
-    /// this limit.
+    /// **Note:**
+    /// - this is the table's capacity, not the number of partitions in use; see
+    ///   [`Fdisk::used_partitions`] to count those.
+    /// - for `MBR` partition tables, this function returns `4`, the maximum number of primary
+    ///   partitions, plus the number of logical partitions currently defined. Use an extended
+    ///   partition to lift the limit of four primary partitions.
     pub fn partition_table_max_partitions(&self) -> usize {
         let max = unsafe { libfdisk::fdisk_get_npartitions(self.inner) };
         log::debug!(
@@ -2926,6 +5631,92 @@ pub fn partition_table_section(&self, nth: i32) -> Option<TableSection> {
         }
     }
 
+    /// Returns the location and size of the in-memory partition table section named `name` (e.g.
+    /// `"GPT Header"`, `"GPT Backup Header"`, `"MBR"`), or `None` if the partition table has no
+    /// section with that name.
+    ///
+    /// Section names are those reported by [`Fdisk::partition_table_section`]. Offsets and sizes
+    /// are expressed in bytes.
+    ///
+    /// **Warning:** `libfdisk` translates most section names according to the process' locale
+    /// (e.g. `"GPT Header"` is only reported as such under the `C` locale, or an English one).
+    /// To locate a section independently of the locale, call [`Fdisk::partition_table_section`]
+    /// with the section's index instead; for example, a `GPT`'s sections are, in order, the
+    /// protective MBR, the header, the entry array, the backup entry array, and the backup header.
+    ///
+    /// Returns a [`FdiskError::Config`] error if the device has no partition table.
+    pub fn partition_table_section_by_name<T>(
+        &self,
+        name: T,
+    ) -> Result<Option<TableSection>, FdiskError>
+    where
+        T: AsRef<str>,
+    {
+        let name = name.as_ref();
+        log::debug!(
+            "Fdisk::partition_table_section_by_name locating partition table section: {:?}",
+            name
+        );
+
+        if !self.device_has_partition_table() {
+            let err_msg = "no partition table on device".to_owned();
+            log::debug!("Fdisk::partition_table_section_by_name {}", err_msg);
+
+            return Err(FdiskError::Config(err_msg));
+        }
+
+        let mut nth = 0;
+        while let Some(section) = self.partition_table_section(nth) {
+            if section.name() == name {
+                log::debug!(
+                    "Fdisk::partition_table_section_by_name located partition table section: {:?}",
+                    section
+                );
+
+                return Ok(Some(section));
+            }
+
+            nth += 1;
+        }
+
+        log::debug!(
+            "Fdisk::partition_table_section_by_name no partition table section named: {:?}",
+            name
+        );
+
+        Ok(None)
+    }
+
+    #[doc(hidden)]
+    /// Converts a `libfdisk`-allocated array of field identifiers to a list of [`Field`]s, then
+    /// frees the array. Identifiers unknown to this library are skipped.
+    fn take_field_ids(id_array_ptr: *mut libc::c_int, len: usize) -> Vec<Field> {
+        if id_array_ptr.is_null() {
+            return vec![];
+        }
+
+        let id_array = unsafe { slice::from_raw_parts(id_array_ptr, len) };
+
+        let fields = id_array
+            .iter()
+            .filter_map(|&id| match Field::try_from(id as u32) {
+                Ok(field) => Some(field),
+                Err(_) => {
+                    log::debug!("Fdisk::take_field_ids skipping unknown field ID: {:?}", id);
+
+                    None
+                }
+            })
+            .collect();
+
+        // Freeing `libfdisk`-allocated array.
+        unsafe {
+            libc::free(id_array_ptr as *mut _);
+        }
+
+        fields
+    }
+
     /// Returns a list of the default fields of a partition entry in a partition table.
     ///
     /// **Note**: the list of default fields depends on
@@ -2952,18 +5743,7 @@ pub fn partition_table_collect_partition_fields(
             0 => {
                 let id_array_ptr = unsafe { array_ptr.assume_init() };
                 let len = unsafe { len_ptr.assume_init() };
-                let id_array = unsafe { slice::from_raw_parts(id_array_ptr, len) };
-
-                let fields = id_array
-                    .iter()
-                    .map(|&id| Field::try_from(id as u32))
-                    .collect::<Result<Vec<Field>, _>>()
-                    .unwrap_or(vec![]);
-
-                // Freeing `libfdisk`-allocated array.
-                unsafe {
-                    libc::free(id_array_ptr as *mut _);
-                }
+                let fields = Self::take_field_ids(id_array_ptr, len);
 
                 log::debug!("Fdisk::partition_table_collect_partition_fields collected partition table field IDs: {:?}", fields);
 
@@ -3023,25 +5803,14 @@ pub fn partition_table_collect_all_partition_fields(
             0 => {
                 let id_array_ptr = unsafe { array_ptr.assume_init() };
                 let len = unsafe { len_ptr.assume_init() };
-                let id_array = unsafe { slice::from_raw_parts(id_array_ptr, len) };
-
-                let fields = id_array
-                    .iter()
-                    .map(|&id| Field::try_from(id as u32))
-                    .collect::<Result<Vec<Field>, _>>()
-                    .unwrap_or(vec![]);
-
-                // Freeing `libfdisk`-allocated array.
-                unsafe {
-                    libc::free(id_array_ptr as *mut _);
-                }
+                let fields = Self::take_field_ids(id_array_ptr, len);
 
                 log::debug!("Fdisk::partition_table_collect_all_partition_fields collected partition table field IDs: {:?}", fields);
 
                 Ok(fields)
             }
             code => {
-                let err_msg = "failed to collect identifiers for partition table fiels".to_owned();
+                let err_msg = "failed to collect identifiers for partition table fields".to_owned();
                 log::debug!("Fdisk::partition_table_collect_all_partition_fields {}. libfdisk::fdisk_label_get_fields_ids_all returned error code: {:?}", err_msg, code);
 
                 Err(FdiskError::Unexpected(err_msg))
@@ -3123,6 +5892,34 @@ pub fn script_mut(&mut self) -> Option<&mut Script> {
         }
     }
 
+    /// Returns the types of partition table supported by the `libfdisk` library in use, whether
+    /// disabled or not.
+    ///
+    /// This is the first column of [`Fdisk::labels_with_state`]. The returned list has
+    /// [`Fdisk::partition_table_count_types`] elements.
+    pub fn supported_labels(&self) -> Vec<PartitionTableKind> {
+        let kinds: Vec<_> = self
+            .labels_with_state()
+            .into_iter()
+            .map(|(kind, _)| kind)
+            .collect();
+        log::debug!("Fdisk::supported_labels value: {:?}", kinds);
+
+        kinds
+    }
+
+    /// Returns the type of each partition table supported by this `Fdisk`, paired with `true`
+    /// when it is disabled (i.e. ignored when scanning the assigned device).
+    pub fn labels_with_state(&self) -> Vec<(PartitionTableKind, bool)> {
+        let states: Vec<_> = self
+            .iter()
+            .map(|table| (table.kind(), table.is_disabled()))
+            .collect();
+        log::debug!("Fdisk::labels_with_state value: {:?}", states);
+
+        states
+    }
+
     //---- END getters
 
     //---- BEGIN iterators
@@ -3155,6 +5952,41 @@ pub fn partition_is_number_in_use(&self, partition_number: usize) -> bool {
         state
     }
 
+    /// Returns `true` when the partition slot matching `partition_number` in the partition table
+    /// is occupied, i.e. it describes a non-empty area of the device.
+    ///
+    /// Unlike [`Fdisk::partition_is_number_in_use`], which returns `false` on failure, this method
+    /// returns a [`FdiskError::Config`] error if the device has no partition table, or if the
+    /// partition table does not support the query.
+    ///
+    /// **Note:** the first partition has identification number `0`.
+    pub fn is_partition_slot_used(&self, partition_number: usize) -> Result<bool, FdiskError> {
+        log::debug!(
+            "Fdisk::is_partition_slot_used checking partition slot: {:?}",
+            partition_number
+        );
+
+        let result = unsafe { libfdisk::fdisk_is_partition_used(self.inner, partition_number) };
+
+        match result {
+            code if code < 0 => {
+                let err_msg = format!(
+                    "failed to check whether partition slot {:?} is used",
+                    partition_number
+                );
+                log::debug!("Fdisk::is_partition_slot_used {}. libfdisk::fdisk_is_partition_used returned error code: {:?}", err_msg, code);
+
+                Err(FdiskError::Config(err_msg))
+            }
+            code => {
+                let state = code == 1;
+                log::debug!("Fdisk::is_partition_slot_used value: {:?}", state);
+
+                Ok(state)
+            }
+        }
+    }
+
     /// Returns `true` when all metadata on the device area specified by the [`Partition`] will be
     /// wiped when the partition table is written to disk.
     pub fn is_partition_wipe_active(&self, partition: &Partition) -> bool {
@@ -3294,8 +6126,22 @@ impl<'a> Drop for Fdisk<'a> {
     fn drop(&mut self) {
         log::debug!("Fdisk::drop deallocating `Fdisk` instance");
 
+        // Other objects (e.g. a `Script`) may hold a reference to this `libfdisk::fdisk_context`,
+        // and outlive it. Detach the prompt callback before releasing it, so that they never call
+        // a deallocated function.
+        if self.prompt_callback.is_some() {
+            unsafe {
+                libfdisk::fdisk_set_ask(self.inner, None, std::ptr::null_mut());
+            }
+        }
+
+        // A nested context holds a reference to its parent's `libfdisk::fdisk_context`, released
+        // here. Since a nested `Fdisk` borrows its parent, it is always dropped first.
         unsafe { libfdisk::fdisk_unref_context(self.inner) }
 
+        // Release the prompt callback, after the context that could call it.
+        Self::drop_prompt_callback(self);
+
         // Release heap allocated PartitionTable references.
         while let Some(gc_item) = self.gc.pop() {
             gc_item.destroy();
@@ -3316,6 +6162,8 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
+    use crate::core::errors::ScriptError;
+    use crate::core::partition::Code;
     use crate::core::partition::Guid;
     use crate::core::partition::Partition;
     use crate::core::partition::PartitionKind;
@@ -3324,6 +6172,8 @@ mod tests {
     use crate::core::partition_table::MaxColWidth;
     use crate::core::partition_table::PartitionTableKind;
     use crate::fdisk::DeviceAddressing;
+    use crate::fdisk::FdiskBuilderError;
+    use crate::fdisk::FdiskGPTExt;
     use crate::fdisk::SizeFormat;
 
     //---- Helper functions
@@ -3381,6 +6231,26 @@ fn blank_image_file() -> NamedTempFile {
         named_file
     }
 
+    // Write MBR partition entries `(type code, starting sector, size in sectors)`, followed by a
+    // boot signature, to a disk image. `libfdisk` refuses to create some of the layouts tests need.
+    fn write_mbr_entries(file: &mut File, entries: &[(u8, u32, u32)]) {
+        use std::io::{Seek, SeekFrom};
+
+        let mut table = [0u8; 66];
+        for (i, (code, start, size)) in entries.iter().enumerate() {
+            let entry = &mut table[i * 16..(i + 1) * 16];
+            entry[4] = *code;
+            entry[8..12].copy_from_slice(&start.to_le_bytes());
+            entry[12..16].copy_from_slice(&size.to_le_bytes());
+        }
+        table[64] = 0x55;
+        table[65] = 0xaa;
+
+        file.seek(SeekFrom::Start(446)).unwrap();
+        file.write_all(&table).unwrap();
+        file.sync_all().unwrap();
+    }
+
     //-------------------------------------------------------------------------
 
     #[test]
@@ -3412,6 +6282,63 @@ fn fdisk_can_not_assign_a_regular_file_by_file_stream() {
             .unwrap();
     }
 
+    #[test]
+    fn fdisk_can_not_assign_a_missing_device() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let result = Fdisk::builder()
+            .assign_device(tmp_dir.path().join("missing.img"))
+            .build();
+
+        assert!(matches!(result, Err(FdiskBuilderError::DeviceNotFound(_))));
+    }
+
+    #[test]
+    fn fdisk_can_not_assign_a_directory() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let result = Fdisk::builder().assign_device(tmp_dir.path()).build();
+
+        assert!(matches!(
+            result,
+            Err(FdiskBuilderError::DeviceIsDirectory(_))
+        ));
+    }
+
+    #[test]
+    fn fdisk_can_resolve_a_symlinked_device_path() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let link = tmp_dir.path().join("disk-by-id-link");
+        std::os::unix::fs::symlink(tmp_image.path(), &link).unwrap();
+
+        let disk = Fdisk::builder().assign_device(&link).build()?;
+
+        let canonical_path = std::fs::canonicalize(tmp_image.path()).unwrap();
+        let actual = disk.device_name();
+        let expected = Some(canonical_path.as_path());
+        assert_eq!(actual, expected);
+
+        let disk = Fdisk::builder()
+            .assign_device(&link)
+            .canonicalize_device_path(false)
+            .build()?;
+
+        let actual = disk.device_name();
+        let expected = Some(link.as_path());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_not_assign_a_character_device() {
+        let result = Fdisk::builder().assign_device("/dev/null").build();
+
+        assert!(matches!(
+            result,
+            Err(FdiskBuilderError::UnsupportedDeviceType(_))
+        ));
+    }
+
     #[test]
     #[should_panic(
         expected = "methods `display_partition_details` and `display_partition_list_only` can not be called at the same time"
@@ -3833,7 +6760,6 @@ fn fdisk_can_override_device_geometry() -> crate::Result<()> {
     }
 
     #[test]
-    #[ignore]
     fn fdisk_can_override_sector_sizes() -> crate::Result<()> {
         let tmp_image = disk_image_with_pt("gpt");
         let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
@@ -3852,8 +6778,6 @@ fn fdisk_can_override_sector_sizes() -> crate::Result<()> {
         let expected = true;
         assert_eq!(actual, expected);
 
-        // FIXME the tests below fail with the actual != expected. How does a user
-        // access the overridden values?
         let actual = disk.device_bytes_per_physical_sector();
         let expected = phys_size as u64;
         assert_eq!(actual, expected);
@@ -4542,4 +7466,3138 @@ fn fdisk_can_partition_a_device() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn fdisk_can_delete_logical_partitions_in_a_safe_order() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+
+        // Extended partition
+        let partition_type = PartitionKind::builder()
+            .code(Code::ExtendedPartition)
+            .build()?;
+        let extended = Partition::builder()
+            .partition_type(partition_type)
+            .number(0)
+            .size_in_sectors(16_384)
+            .build()?;
+        disk.partition_add(extended)?;
+
+        // Logical partitions 4, 5, 6, with different sizes to tell them apart.
+        for (number, size) in [(4, 2_048), (5, 4_096), (6, 6_144)] {
+            let partition_type = PartitionKind::builder().code(Code::Linux).build()?;
+            let logical = Partition::builder()
+                .partition_type(partition_type)
+                .number(number)
+                .size_in_sectors(size)
+                .build()?;
+            disk.partition_add(logical)?;
+        }
+
+        // Unknown partition number, nothing is deleted.
+        let result = disk.delete_partitions(&[4, 9]);
+        assert!(result.is_err());
+
+        let actual = disk.list_partitions().unwrap().len();
+        let expected = 4;
+        assert_eq!(actual, expected);
+
+        // Deleting in increasing order would have removed partition 4 then failed to find 6.
+        disk.delete_partitions(&[4, 6])?;
+
+        let actual = disk.list_partitions().unwrap().len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        // The former partition 5 is now partition 4.
+        let actual = disk.partition_is_number_in_use(5);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_by_number(4).unwrap().size_in_sectors();
+        let expected = Some(4_096);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_a_partition_size_in_bytes() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+        let size = 4_096; // 512 bytes per sector, 4,096 sectors <=> 2MiB
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .size_in_sectors(size)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let actual = disk.partition_size_in_bytes(0)?;
+        let expected = Some(2_097_152);
+        assert_eq!(actual, expected);
+
+        let start = disk
+            .partition_by_number(0)
+            .unwrap()
+            .starting_sector()
+            .unwrap();
+        let actual = disk.partition_bytes_range(0)?;
+        let expected = Some((start * 512)..((start + size) * 512));
+        assert_eq!(actual, expected);
+
+        let result = disk.partition_size_in_bytes(1);
+        assert!(matches!(result, Err(FdiskError::NoSuchPartition(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_zero_a_device_region() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let sectors = disk.device_size_in_sectors();
+        let result = disk.zero_device_region(sectors - 1, 2);
+        assert!(result.is_err());
+
+        // Erase the protective MBR and primary GPT header.
+        disk.zero_device_region(0, 2)?;
+
+        let content = std::fs::read(tmp_image.path()).unwrap();
+        let actual = content[..1024].iter().all(|&b| b == 0);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_list_partition_table_types_fitting_a_small_device() -> crate::Result<()> {
+        // 16 KiB device, too small for a GPT partition table.
+        let mut tmp_image = NamedTempFile::new().unwrap();
+        tmp_image.as_file_mut().write_all(&[0u8; 16_384]).unwrap();
+
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let kinds = disk.labels_fitting_device();
+
+        let actual = kinds.contains(&PartitionTableKind::GPT);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let actual = kinds.contains(&PartitionTableKind::DOS);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk
+            .labels_fitting_device()
+            .contains(&PartitionTableKind::GPT);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_the_default_first_partition_lba_on_a_gpt_device() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let actual = disk.default_first_partition_lba();
+        let expected = 2048;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_the_default_first_partition_lba_on_a_dos_device() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+
+        let actual = disk.default_first_partition_lba();
+        let expected = 2048;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_summarize_a_gpt_partition_table() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let summary = disk.summary()?;
+
+        let actual = summary.kind();
+        let expected = PartitionTableKind::GPT;
+        assert_eq!(actual, expected);
+
+        let actual = summary.id();
+        let expected = Some("DD27F98D-7519-4C9E-8041-F2BFA7B1EF61");
+        assert_eq!(actual, expected);
+
+        let actual = summary.first_usable_lba();
+        let expected = 34;
+        assert_eq!(actual, expected);
+
+        let actual = summary.last_usable_lba();
+        let expected = 20446;
+        assert_eq!(actual, expected);
+
+        let actual = summary.max_partitions();
+        let expected = 128;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_not_summarize_a_device_without_partition_table() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.summary().is_err();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_a_non_zero_optimal_io_size_on_an_image_file() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.device_optimal_io_size();
+        let expected = 512;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_map_partition_types() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let mut partitions = PartitionList::new()?;
+        for guid in [Guid::LinuxData, Guid::LinuxSwap, Guid::LinuxData] {
+            let partition_type = PartitionKind::builder().guid(guid).build()?;
+            let partition = Partition::builder()
+                .partition_type(partition_type)
+                .size_in_sectors(2_048)
+                .build()?;
+
+            partitions.push(partition)?;
+        }
+        disk.partitions_append(partitions)?;
+
+        let actual = disk.map_partition_types(|guid| match guid {
+            Guid::LinuxData => Some(Guid::LinuxRootx86_64),
+            _ => None,
+        })?;
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        let partitions = disk.list_partitions().unwrap();
+        let actual: Vec<_> = partitions
+            .iter()
+            .filter_map(|partition| partition.partition_type())
+            .filter_map(|kind| kind.guid().and_then(|s| s.parse::<Guid>().ok()))
+            .collect();
+        let expected = vec![
+            Guid::LinuxRootx86_64,
+            Guid::LinuxSwap,
+            Guid::LinuxRootx86_64,
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_look_up_partition_types_by_guid_or_code() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let gpt = disk
+            .partition_table_by_type(PartitionTableKind::GPT)
+            .unwrap();
+
+        let actual = gpt
+            .guid_type(Guid::LinuxData)
+            .and_then(|kind| kind.guid().and_then(|s| s.parse::<Guid>().ok()));
+        let expected = Some(Guid::LinuxData);
+        assert_eq!(actual, expected);
+
+        let actual = gpt.code_type(Code::Linux).is_none();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let dos = disk
+            .partition_table_by_type(PartitionTableKind::DOS)
+            .unwrap();
+
+        let actual = dos.code_type(Code::Linux).and_then(|kind| kind.code());
+        let expected = Some(Code::Linux.to_u32());
+        assert_eq!(actual, expected);
+
+        let actual = dos.guid_type(Guid::LinuxData).is_none();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_enable_only_some_partition_table_types() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_only_labels(&[PartitionTableKind::GPT])
+            .build()?;
+
+        let actual = disk.device_has_partition_table();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let tmp_image = disk_image_with_pt("sun");
+        let disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_only_labels(&[PartitionTableKind::GPT])
+            .build()?;
+
+        let actual = disk.device_has_partition_table();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_align_an_lba_within_device_bounds() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        // 1 MiB grain <=> 2048 sectors, last usable LBA 20446.
+        let last_lba = disk.device_last_lba();
+
+        let actual = disk.align_lba_up_bounded(last_lba - 10);
+        let expected = 18432;
+        assert_eq!(actual, expected);
+
+        let actual = disk.align_lba_down_bounded(last_lba - 10);
+        let expected = 18432;
+        assert_eq!(actual, expected);
+
+        let actual = disk.align_lba_down_bounded(last_lba + 100);
+        let expected = 18432;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_add_a_partition_in_a_free_region() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        // Leave a gap between two partitions.
+        for start in [2_048, 8_192] {
+            let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+            let partition = Partition::builder()
+                .partition_type(partition_type)
+                .starting_sector(start)
+                .size_in_sectors(2_048)
+                .build()?;
+            disk.partition_add(partition)?;
+        }
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxSwap).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .size_in_sectors(2_048)
+            .build()?;
+        let partition_number = disk.partition_add_in_free_region(partition, 0)?;
+
+        let actual = disk
+            .partition_by_number(partition_number)
+            .and_then(|partition| partition.starting_sector());
+        let expected = Some(4_096);
+        assert_eq!(actual, expected);
+
+        let partition = Partition::builder().build()?;
+        let result = disk.partition_add_in_free_region(partition, 42);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_report_write_stages() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let mut stages = Vec::new();
+        disk.write_partition_table_with(|stage| stages.push(stage))?;
+
+        let actual = stages;
+        let expected = vec![
+            WriteStage::Started,
+            WriteStage::Written,
+            WriteStage::Verified,
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_list_used_and_free_partitions() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(2_048)
+            .size_in_sectors(4_096)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let used = disk.used_partitions()?;
+        let actual = used.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let actual = used.iter().all(|partition| partition.points_to_used_area());
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let free = disk.list_empty_spaces().unwrap();
+        let actual = free.is_empty();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let actual = free.iter().all(|partition| partition.is_free_space());
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_script_import_error_reports_the_offending_line() -> crate::Result<()> {
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(b"label: gpt\nunit: sectors\nstart=2048, size=, type=, bogus\n")
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let script = disk.script_new()?;
+        let result = script.import_file(script_file.path());
+
+        let actual = match result {
+            Err(ScriptError::Read(err_msg)) => err_msg.contains("line 3"),
+            _ => false,
+        };
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_wipe_collisions_and_create_a_partition_table() -> crate::Result<()> {
+        let tmp_image = disk_image_with_fs("ext4");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.device_has_collisions();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        disk.wipe_collisions_and_create(PartitionTableKind::GPT)?;
+
+        let actual = disk.wipes_device_metadata();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_table_is_of_type(PartitionTableKind::GPT);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_locate_the_gpt_backup_header() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.gpt_backup_header_lba()?;
+        let expected = Some(disk.device_size_in_sectors() - 1);
+        assert_eq!(actual, expected);
+
+        let tmp_image = disk_image_with_pt("sun");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.gpt_backup_header_lba()?;
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_create_a_gpt_partition_table_on_a_blank_device() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.device_has_partition_table();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let actual = disk.device_has_partition_table();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_not_create_a_disabled_partition_table_type() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .enable_only_labels(&[PartitionTableKind::DOS])
+            .build()?;
+
+        let result = disk.partition_table_create(PartitionTableKind::GPT);
+        assert!(matches!(result, Err(FdiskError::Creation(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_not_write_a_partition_table_to_a_read_only_device() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.partition_table_write_to_disk();
+        assert!(matches!(result, Err(FdiskError::IoError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_read_back_a_partition_table_written_to_disk() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let result = disk.partition_table_write_to_disk();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(2_048)
+            .size_in_sectors(4_096)
+            .build()?;
+        let partition_number = disk.partition_add(partition)?;
+
+        disk.partition_table_write_to_disk()?;
+        disk.close_device()?;
+
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let partition = disk.partition_by_number(partition_number).unwrap();
+
+        let actual = partition.starting_sector();
+        let expected = Some(2_048);
+        assert_eq!(actual, expected);
+
+        let actual = partition.size_in_sectors();
+        let expected = Some(4_096);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_expand_a_partition_type_shortcut() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let kind = disk.expand_type_shortcut("L")?;
+        let actual = kind.guid().map(str::to_lowercase);
+        let expected = Some(Guid::LinuxData.to_string());
+        assert_eq!(actual, expected);
+
+        let kind = disk.expand_type_shortcut("swap")?;
+        let actual = kind.guid().map(str::to_lowercase);
+        let expected = Some(Guid::LinuxSwap.to_string());
+        assert_eq!(actual, expected);
+
+        let result = disk.expand_type_shortcut("not-a-shortcut");
+        assert!(matches!(result, Err(ParserError::Shortcut(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_report_problems_in_an_overlapping_dos_layout() -> crate::Result<()> {
+        use std::io::{Seek, SeekFrom};
+
+        let mut tmp_image = blank_image_file();
+
+        // Write an MBR with two overlapping Linux partitions by hand, since `libfdisk` refuses
+        // to create them.
+        let mut entries = [0u8; 66];
+        for (i, (start, size)) in [(2_048u32, 4_096u32), (4_096, 4_096)].iter().enumerate() {
+            let entry = &mut entries[i * 16..(i + 1) * 16];
+            entry[4] = 0x83;
+            entry[8..12].copy_from_slice(&start.to_le_bytes());
+            entry[12..16].copy_from_slice(&size.to_le_bytes());
+        }
+        entries[64] = 0x55;
+        entries[65] = 0xaa;
+
+        let file = tmp_image.as_file_mut();
+        file.seek(SeekFrom::Start(446)).unwrap();
+        file.write_all(&entries).unwrap();
+        file.sync_all().unwrap();
+
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let report = disk.verify_partition_table()?;
+
+        let actual = report.has_issues();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = report.messages().is_empty();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_set_and_read_back_a_gpt_partition_table_id() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let id = "1A2B3C4D-5E6F-4A0B-9C8D-7E6F5A4B3C2D";
+        disk.partition_table_set_string_id(id)?;
+
+        let actual = disk.partition_table_id();
+        let expected = Some(id.to_owned());
+        assert_eq!(actual, expected);
+
+        let result = disk.partition_table_set_string_id("0x1234abcd");
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_randomize_a_gpt_disk_guid() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let first = disk.randomize_gpt_disk_guid()?;
+
+        let actual = disk.partition_table_id();
+        let expected = Some(first.clone());
+        assert_eq!(actual, expected);
+
+        let actual = first.chars().nth(14);
+        let expected = Some('4');
+        assert_eq!(actual, expected);
+
+        let actual = first.chars().nth(19).map(|c| "89AB".contains(c));
+        let expected = Some(true);
+        assert_eq!(actual, expected);
+
+        let second = disk.randomize_gpt_disk_guid()?;
+        assert_ne!(first, second);
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+        let result = disk.randomize_gpt_disk_guid();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_find_the_efi_system_partition() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(2_048)
+            .size_in_sectors(2_048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let actual = disk.find_efi_system_partition()?.is_none();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let partition_type = PartitionKind::builder().guid(Guid::EfiSystem).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(4_096)
+            .size_in_sectors(2_048)
+            .build()?;
+        let esp_number = disk.partition_add(partition)?;
+
+        let actual = disk
+            .find_efi_system_partition()?
+            .and_then(|partition| partition.number());
+        let expected = Some(esp_number);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_add_a_partition_at_the_default_starting_sector() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        // 4MiB partition, 512 bytes per sector.
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .size_in_sectors(8_192)
+            .build()?;
+
+        let actual = disk.partition_add(partition)?;
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual = disk.device_has_partition_table();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        // The partition starts at the first aligned sector.
+        let actual = disk
+            .partition_by_number(0)
+            .and_then(|partition| partition.starting_sector());
+        let expected = Some(disk.default_first_partition_lba());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_apply_partition_table_headers() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let id = "1A2B3C4D-5E6F-4A0B-9C8D-7E6F5A4B3C2D";
+        let headers = BTreeMap::from([
+            ("label".to_owned(), "gpt".to_owned()),
+            ("label-id".to_owned(), id.to_owned()),
+            ("first-lba".to_owned(), "4096".to_owned()),
+            ("last-lba".to_owned(), "16383".to_owned()),
+        ]);
+
+        disk.apply_headers(&headers)?;
+
+        let actual = disk.partition_table_is_of_type(PartitionTableKind::GPT);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_table_id();
+        let expected = Some(id.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual = disk
+            .partition_table_header_entry(HeaderEntry::GptFirstUsableLba)
+            .unwrap()
+            .data_u64();
+        let expected = Some(4_096);
+        assert_eq!(actual, expected);
+
+        let actual = disk
+            .partition_table_header_entry(HeaderEntry::GptLastUsableLba)
+            .unwrap()
+            .data_u64();
+        let expected = Some(16_383);
+        assert_eq!(actual, expected);
+
+        // Invalid headers leave the partition table untouched.
+        let invalid_headers = [
+            vec![("label", "dos"), ("label-id", "not an identifier")],
+            vec![("label", "dos"), ("table-length", "64")],
+            vec![("label", "gpt"), ("first-lba", "1")],
+            vec![("first-lba", "8192"), ("last-lba", "4096")],
+        ];
+
+        for invalid in invalid_headers {
+            let headers: BTreeMap<String, String> = invalid
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect();
+            let result = disk.apply_headers(&headers);
+            assert!(matches!(result, Err(FdiskError::Config(_))));
+
+            let actual = disk.partition_table_is_of_type(PartitionTableKind::GPT);
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            let actual = disk.partition_table_id();
+            let expected = Some(id.to_owned());
+            assert_eq!(actual, expected);
+        }
+
+        let headers = BTreeMap::from([("unknown".to_owned(), "value".to_owned())]);
+        let result = disk.apply_headers(&headers);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        let headers = BTreeMap::from([("first-lba".to_owned(), "not a number".to_owned())]);
+        let result = disk.apply_headers(&headers);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_delete_a_partition() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        for start in [2_048, 4_096] {
+            let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+            let partition = Partition::builder()
+                .partition_type(partition_type)
+                .starting_sector(start)
+                .size_in_sectors(2_048)
+                .build()?;
+            disk.partition_add(partition)?;
+        }
+
+        disk.partition_delete(0)?;
+
+        let actual = disk.used_partitions()?.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let result = disk.partition_delete(0);
+        assert!(matches!(result, Err(FdiskError::NoSuchPartition(_))));
+
+        disk.partition_delete_all()?;
+
+        let actual = disk.used_partitions()?.len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_a_partition_by_number() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let partition = disk.get_partition(0)?;
+
+        let actual = partition.starting_sector();
+        let expected = Some(34);
+        assert_eq!(actual, expected);
+
+        let actual = partition.size_in_sectors();
+        let expected = Some(2_014);
+        assert_eq!(actual, expected);
+
+        let actual = partition.name();
+        let expected = Some("ThisIsName");
+        assert_eq!(actual, expected);
+
+        let actual = partition
+            .partition_type()
+            .and_then(|kind| kind.guid().and_then(|guid| guid.parse::<Guid>().ok()));
+        let expected = Some(Guid::WindowsBasicData);
+        assert_eq!(actual, expected);
+
+        assert!(!partition.is_free_space());
+
+        let result = disk.get_partition(4_096);
+        assert!(matches!(result, Err(FdiskError::NoSuchPartition(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_convert_a_partition_row_to_string() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let fields = [Field::StartingLba, Field::EndingLba, Field::Name];
+        let actual = disk.partition_row(0, &fields)?;
+        let expected = vec![
+            String::from("34"),
+            String::from("2047"),
+            String::from("ThisIsName"),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_row(0, &[])?;
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_tell_when_there_are_unsaved_changes() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        assert!(!disk.has_unsaved_changes());
+
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        assert!(!disk.has_unsaved_changes());
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        assert!(disk.has_unsaved_changes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_adopt_a_partition_table_configuration() -> crate::Result<()> {
+        let template_image = blank_image_file();
+        let mut template_disk = Fdisk::builder()
+            .assign_device(template_image.path())
+            .build()?;
+
+        template_disk
+            .partition_table_by_type_mut(PartitionTableKind::DOS)
+            .unwrap()
+            .dos_enable_compatible_mode()?;
+
+        let template = template_disk
+            .partition_table_by_type(PartitionTableKind::DOS)
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.adopt_partition_table(template)?;
+
+        let table = disk.partition_table_current().unwrap();
+
+        let actual = table.kind();
+        let expected = PartitionTableKind::DOS;
+        assert_eq!(actual, expected);
+
+        assert!(table.is_dos_compatible());
+
+        let actual = disk.used_partitions()?.len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_list_the_trailing_free_space() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(2_048)
+            .size_in_sectors(2_048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let free = disk.list_empty_spaces().unwrap();
+        let trailing = free.iter().last().unwrap();
+
+        assert!(trailing.is_free_space());
+
+        let actual = trailing.number();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        let actual = trailing.starting_sector();
+        let expected = Some(4_096);
+        assert_eq!(actual, expected);
+
+        // 10MB image: 20_480 sectors, the last usable one is 20_446 (GPT backup header and entry
+        // array take the last 33 sectors).
+        let actual = trailing.size_in_sectors();
+        let expected = Some(16_351);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_iterate_over_partition_tables() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let kinds: Vec<_> = disk.iter().map(|table| table.kind()).collect();
+
+        let actual = kinds.len();
+        let expected = 5;
+        assert_eq!(actual, expected);
+
+        for kind in [
+            PartitionTableKind::BSD,
+            PartitionTableKind::DOS,
+            PartitionTableKind::GPT,
+            PartitionTableKind::SGI,
+            PartitionTableKind::SUN,
+        ] {
+            assert!(kinds.contains(&kind));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_apply_a_partition_list() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let mut list = PartitionList::new()?;
+        for start in [2_048, 4_096, 6_144] {
+            let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+            let partition = Partition::builder()
+                .partition_type(partition_type)
+                .starting_sector(start)
+                .size_in_sectors(2_048)
+                .build()?;
+            list.push(partition)?;
+        }
+
+        disk.apply_table(&list)?;
+
+        let partitions = disk.used_partitions()?;
+        let actual: Vec<_> = partitions
+            .iter()
+            .map(|partition| partition.starting_sector())
+            .collect();
+        let expected = vec![Some(2_048), Some(4_096), Some(6_144)];
+        assert_eq!(actual, expected);
+
+        // The second entry does not fit on the device.
+        let mut list = PartitionList::new()?;
+        for (start, size) in [(8_192, 2_048), (10_240, 1_000_000)] {
+            let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+            let partition = Partition::builder()
+                .partition_type(partition_type)
+                .starting_sector(start)
+                .size_in_sectors(size)
+                .build()?;
+            list.push(partition)?;
+        }
+
+        let result = disk.apply_table(&list);
+        assert!(matches!(&result, Err(FdiskError::Config(msg)) if msg.contains("index 1")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_override_a_partition_type() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        for start in [2_048, 4_096] {
+            let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+            let partition = Partition::builder()
+                .partition_type(partition_type)
+                .starting_sector(start)
+                .size_in_sectors(2_048)
+                .build()?;
+            disk.partition_add(partition)?;
+        }
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxSwap).build()?;
+        let template = Partition::builder()
+            .partition_type(partition_type)
+            .build()?;
+        disk.partition_override_settings(1, &template)?;
+
+        let partition = disk.get_partition(1)?;
+
+        let actual = partition
+            .partition_type()
+            .and_then(|kind| kind.guid().and_then(|guid| guid.parse::<Guid>().ok()));
+        let expected = Some(Guid::LinuxSwap);
+        assert_eq!(actual, expected);
+
+        let actual = partition.starting_sector();
+        let expected = Some(4_096);
+        assert_eq!(actual, expected);
+
+        let result = disk.partition_override_settings(42, &template);
+        assert!(matches!(result, Err(FdiskError::NoSuchPartition(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_read_raw_gpt_entry_bytes() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let entry = disk.gpt_partition_entry_bytes(0)?.unwrap();
+
+        // Starting LBA
+        let actual = u64::from_le_bytes(entry[32..40].try_into().unwrap());
+        let expected = 34;
+        assert_eq!(actual, expected);
+
+        // Ending LBA
+        let actual = u64::from_le_bytes(entry[40..48].try_into().unwrap());
+        let expected = 2_047;
+        assert_eq!(actual, expected);
+
+        let actual = disk.gpt_partition_entry_bytes(10)?;
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        let result = disk.gpt_partition_entry_bytes(4_096);
+        assert!(matches!(result, Err(FdiskError::NoSuchPartition(_))));
+
+        let tmp_image = disk_image_with_pt("sun");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.gpt_partition_entry_bytes(0);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_toggle_named_gpt_partition_attributes() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition_type = PartitionKind::builder()
+            .guid(Guid::WindowsBasicData)
+            .build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(2_048)
+            .size_in_sectors(2_048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        disk.gpt_set_partition_read_only(0, true)?;
+        let actual = disk.gpt_attribute_bits(0);
+        let expected = Some(1u64 << 60);
+        assert_eq!(actual, expected);
+
+        disk.gpt_set_partition_hidden(0, true)?;
+        disk.gpt_set_partition_no_automount(0, true)?;
+        let actual = disk.gpt_attribute_bits(0);
+        let expected = Some((1u64 << 60) | (1u64 << 62) | (1u64 << 63));
+        assert_eq!(actual, expected);
+
+        disk.gpt_set_partition_read_only(0, false)?;
+        let actual = disk.gpt_attribute_bits(0);
+        let expected = Some((1u64 << 62) | (1u64 << 63));
+        assert_eq!(actual, expected);
+
+        let tmp_image = disk_image_with_pt("sun");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let result = disk.gpt_set_partition_hidden(0, true);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_detect_overlapping_partitions() -> crate::Result<()> {
+        use std::io::{Seek, SeekFrom};
+
+        let mut tmp_image = blank_image_file();
+
+        // Write an MBR with two overlapping Linux partitions, and a third one apart, by hand
+        // since `libfdisk` refuses to create them.
+        let mut entries = [0u8; 66];
+        for (i, (start, size)) in [(2_048u32, 4_096u32), (4_096, 4_096), (10_240, 2_048)]
+            .iter()
+            .enumerate()
+        {
+            let entry = &mut entries[i * 16..(i + 1) * 16];
+            entry[4] = 0x83;
+            entry[8..12].copy_from_slice(&start.to_le_bytes());
+            entry[12..16].copy_from_slice(&size.to_le_bytes());
+        }
+        entries[64] = 0x55;
+        entries[65] = 0xaa;
+
+        let file = tmp_image.as_file_mut();
+        file.seek(SeekFrom::Start(446)).unwrap();
+        file.write_all(&entries).unwrap();
+        file.sync_all().unwrap();
+
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.detect_overlaps()?;
+        let expected = vec![(0, 1)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_toggle_the_active_flag_of_a_dos_partition() -> crate::Result<()> {
+        use crate::core::partition::DOSFlag;
+        use crate::core::partition::GPTFlag;
+
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+
+        let partition_type = PartitionKind::builder().code(Code::Linux).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(2_048)
+            .size_in_sectors(2_048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        assert!(!disk.get_partition(0)?.is_bootable());
+
+        disk.partition_table_toggle_partition_flag(0, BitFlag::from(DOSFlag::Boot))?;
+        assert!(disk.get_partition(0)?.is_bootable());
+
+        disk.partition_table_toggle_partition_flag(0, BitFlag::from(DOSFlag::Boot))?;
+        assert!(!disk.get_partition(0)?.is_bootable());
+
+        let result = disk
+            .partition_table_toggle_partition_flag(0, BitFlag::from(GPTFlag::RequiredPartition));
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_has_no_partition_device_path_for_image_files() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.partition_device_path(0);
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_set_and_read_back_gpt_attribute_bits() -> crate::Result<()> {
+        use crate::core::partition::GPTFlag;
+
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(2_048)
+            .size_in_sectors(2_048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let bits = (1u64 << GPTFlag::Bit63.attribute_bit().unwrap())
+            | (1u64 << GPTFlag::Bit48.attribute_bit().unwrap());
+        disk.gpt_set_attribute_bits(0, bits)?;
+
+        let actual = disk.gpt_attribute_bits(0);
+        let expected = Some(bits);
+        assert_eq!(actual, expected);
+
+        // Bit 3 is reserved by the UEFI specification.
+        let result = disk.gpt_set_attribute_bits(0, 1u64 << 3);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_name_a_partition_after_its_device() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.partition_name(0);
+        let expected = misc::partition_name(tmp_image.path(), 1);
+        assert_eq!(actual, expected);
+        assert!(actual.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_verify_a_written_partition_table() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        for start in [2_048, 4_096] {
+            let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+            let partition = Partition::builder()
+                .partition_type(partition_type)
+                .starting_sector(start)
+                .size_in_sectors(2_048)
+                .build()?;
+            disk.partition_add(partition)?;
+        }
+
+        disk.partition_table_write_to_disk()?;
+
+        let actual = disk.verify_written()?;
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let partition_type = PartitionKind::builder().guid(Guid::LinuxData).build()?;
+        let partition = Partition::builder()
+            .partition_type(partition_type)
+            .starting_sector(6_144)
+            .size_in_sectors(2_048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let actual = disk.verify_written()?;
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_toggle_dos_compatible_mode() -> crate::Result<()> {
+        use crate::fdisk::FdiskDOSExt;
+
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+
+        let table = disk.partition_table_current_mut().unwrap();
+
+        table.dos_enable_compatible_mode()?;
+        assert!(table.is_dos_compatible());
+
+        table.dos_disable_compatible_mode()?;
+        assert!(!table.is_dos_compatible());
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let result = disk.dos_relocate_partition(0);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(fdisk = "v2_39")]
+    fn fdisk_can_fix_dos_chs_values() -> crate::Result<()> {
+        use crate::fdisk::FdiskDOSExt;
+        use std::io::{Seek, SeekFrom};
+
+        let mut tmp_image = blank_image_file();
+        {
+            let mut disk = Fdisk::builder()
+                .assign_device(tmp_image.path())
+                .enable_read_write()
+                .wipe_device_metadata()
+                .build()?;
+
+            disk.partition_table_create(PartitionTableKind::DOS)?;
+
+            for start in [2_048, 4_096] {
+                let partition_type = PartitionKind::builder().code(Code::Linux).build()?;
+                let partition = Partition::builder()
+                    .partition_type(partition_type)
+                    .starting_sector(start)
+                    .size_in_sectors(2_048)
+                    .build()?;
+                disk.partition_add(partition)?;
+            }
+
+            disk.partition_table_write_to_disk()?;
+        }
+
+        // Overwrite the starting CHS address of the first partition.
+        let file = tmp_image.as_file_mut();
+        file.seek(SeekFrom::Start(446 + 1)).unwrap();
+        file.write_all(&[0xfe, 0xff, 0xff]).unwrap();
+        file.sync_all().unwrap();
+
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.dos_fix_chs_values()?;
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let actual = disk.dos_fix_chs_values()?;
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_read_sgi_and_sun_header_values() -> crate::Result<()> {
+        use crate::fdisk::FdiskSGIExt;
+        use crate::fdisk::FdiskSUNExt;
+
+        let tmp_image = disk_image_with_pt("sgi");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.sgi_boot_file()?;
+        let expected = Some(String::from("/unix"));
+        assert_eq!(actual, expected);
+
+        let result = disk.sun_rotation_per_minute();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        let tmp_image = disk_image_with_pt("sun");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.sun_rotation_per_minute()?;
+        let expected = 5400;
+        assert_eq!(actual, expected);
+
+        let actual = disk.sun_alternate_cylinder_count()?;
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        let actual = disk.sun_physical_cylinder_count()?;
+        let expected = 65535;
+        assert_eq!(actual, expected);
+
+        let actual = disk.sun_extra_sectors_per_cylinder()?;
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual = disk.sun_interleave_factor()?;
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let actual = disk.sun_volume_id()?;
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        let result = disk.sgi_boot_file();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_recreate_a_gpt_preserving_its_disk_guid() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let disk_guid = disk.partition_table_id();
+        assert!(disk_guid.is_some());
+
+        disk.recreate_gpt_preserving_id()?;
+
+        let actual = disk.partition_table_id();
+        let expected = disk_guid;
+        assert_eq!(actual, expected);
+
+        let actual = disk.used_partitions()?.len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let tmp_image = disk_image_with_pt("sun");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let result = disk.recreate_gpt_preserving_id();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_set_the_sgi_boot_file_without_prompting() -> crate::Result<()> {
+        use crate::fdisk::FdiskSGIExt;
+
+        let tmp_image = disk_image_with_pt("sgi");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.sgi_set_boot_file_path("/boot/vmlinux")?;
+
+        let actual = disk.sgi_boot_file()?;
+        let expected = Some(String::from("/boot/vmlinux"));
+        assert_eq!(actual, expected);
+
+        let result = disk.sgi_set_boot_file_path("/a/path/much/too/long/for/sgi");
+        assert!(matches!(result, Err(FdiskError::BootFile(_))));
+
+        let result = disk.sgi_set_boot_file_path("relative/unix");
+        assert!(matches!(result, Err(FdiskError::BootFile(_))));
+
+        // A rejected path leaves the previous value untouched.
+        let actual = disk.sgi_boot_file()?;
+        let expected = Some(String::from("/boot/vmlinux"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_checks_bsd_disklabel_operations_preconditions() -> crate::Result<()> {
+        use crate::fdisk::FdiskBSDExt;
+
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.bsd_install_bootstrap_file();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        let result = disk.bsd_link_to_nested_partition();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        let tmp_image = disk_image_with_pt("bsd");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.bsd_edit_disk_label();
+        assert!(matches!(result, Err(FdiskError::DialogsDisabled(_))));
+
+        // Not a nested partitioner.
+        let result = disk.bsd_link_to_nested_partition();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_create_a_nested_partitioner_for_a_bsd_disklabel() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("dos_bsd");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let nested = disk.create_nested_partitioner_for(PartitionTableKind::BSD)?;
+
+        let actual = nested.partition_table_is_of_type(PartitionTableKind::BSD);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = nested.list_partitions().is_some();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        // A BSD disklabel lives in a DOS partition.
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.create_nested_partitioner_for(PartitionTableKind::BSD);
+        assert!(matches!(result, Err(FdiskError::Creation(_))));
+
+        let tmp_image = disk_image_with_pt("dos_bsd");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.create_nested_partitioner_for(PartitionTableKind::SGI);
+        assert!(matches!(result, Err(FdiskError::Creation(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_edit_a_nested_bsd_disklabel() -> crate::Result<()> {
+        use crate::fdisk::FdiskBSDExt;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let tmp_image = disk_image_with_pt("dos_bsd");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .enable_interactive()
+            .build()?;
+
+        let mut nested = disk.create_nested_partitioner_for(PartitionTableKind::BSD)?;
+
+        // Keep the current values of the disklabel's fields.
+        let questions = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&questions);
+        nested.set_prompt_callback(move |prompt| {
+            if prompt.kind() == PromptKind::Number {
+                counter.set(counter.get() + 1);
+                let default = prompt.number_default();
+                prompt
+                    .number_set_answer(default)
+                    .map_err(|e| FdiskError::Prompt(e.to_string()))
+            } else {
+                Ok(())
+            }
+        })?;
+
+        nested.bsd_edit_disk_label()?;
+
+        let actual = questions.get() > 0;
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = nested.partition_table_is_of_type(PartitionTableKind::BSD);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_commit_changes_to_disk() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_delete(3)?;
+        disk.commit()?;
+
+        let actual = disk.verify_written()?;
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.used_partitions()?.len();
+        let expected = 3;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_table_can_look_up_a_field_by_column_name() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let table = disk.partition_table_current().unwrap();
+
+        let actual = table.field_by_name("Start");
+        let expected = Some(Field::StartingLba);
+        assert_eq!(actual, expected);
+
+        let actual = table.field_by_name("type-uuid");
+        let expected = Some(Field::TypeId);
+        assert_eq!(actual, expected);
+
+        let actual = table.field_by_name("NoSuchColumn");
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_be_created_from_an_sfdisk_dump() -> crate::Result<()> {
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\nstart=2048, size=2048, type=L\nstart=4096, size=4096, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let script = Script::new_from_file(&disk, script_file.path())?;
+
+        let actual = script.count_lines();
+        let expected = Some(5);
+        assert_eq!(actual, expected);
+
+        let actual = script
+            .partition_table_entries()
+            .map(|entries| entries.len());
+        let expected = Some(2);
+        assert_eq!(actual, expected);
+
+        let script = Script::new(&disk)?;
+
+        let actual = script.has_header_label();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_be_validated_for_a_device() -> crate::Result<()> {
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\nstart=2048, size=2048, type=L\nstart=4096, size=4096, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let script = Script::new_from_file(&disk, script_file.path())?;
+
+        let actual = script.validate_for_device(&disk);
+        let expected = Ok(());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_not_be_validated_for_a_device_with_overlapping_partitions() -> crate::Result<()> {
+        // The first partition overlaps both of the others, which do not overlap each other.
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\nstart=2048, size=8192, type=L\nstart=4096, size=2048, type=L\nstart=8192, size=2048, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let script = Script::new_from_file(&disk, script_file.path())?;
+
+        let problems = script.validate_for_device(&disk).unwrap_err();
+
+        let actual = problems
+            .iter()
+            .filter(|problem| problem.contains("overlaps"))
+            .count();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_be_validated_for_a_device_with_its_own_usable_sectors() -> crate::Result<()> {
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(
+                b"label: gpt\nunit: sectors\nlast-lba: 8191\n\nstart=2048, size=2048, type=L\nstart=4096, size=8192, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let script = Script::new_from_file(&disk, script_file.path())?;
+
+        let problems = script.validate_for_device(&disk).unwrap_err();
+
+        let actual = problems.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let actual =
+            problems[0].ends_with("ends at sector 12287, beyond the last usable sector 8191");
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_list_partition_tables_with_their_disabled_state() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk
+            .labels_with_state()
+            .iter()
+            .any(|(_, disabled)| *disabled);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        disk.partition_table_by_type_mut(PartitionTableKind::SUN)
+            .unwrap()
+            .disable();
+
+        let states = disk.labels_with_state();
+
+        let actual = states.len();
+        let expected = 5;
+        assert_eq!(actual, expected);
+
+        for (kind, disabled) in states {
+            let actual = disabled;
+            let expected = kind == PartitionTableKind::SUN;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_apply_a_script_and_its_headers() -> crate::Result<()> {
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(
+                b"label: gpt\nlabel-id: DD27F98D-7519-4C9E-8041-F2BFA7B1EF61\nunit: sectors\n\nstart=2048, size=2048, type=0FC63DAF-8483-4772-8E79-3D69D8477DE4\nstart=4096, size=4096, type=0657FD6D-A4AB-43C4-84E5-0933C84B4F4F\n",
+            )
+            .unwrap();
+
+        // Apply the whole script.
+        let tmp_image = blank_image_file();
+        let reader = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let script = Script::new_from_file(&reader, script_file.path())?;
+
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+        disk.script_apply(&script)?;
+
+        let actual = disk.partition_table_is_of_type(PartitionTableKind::GPT);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let first = disk.get_partition(0)?;
+        let actual = (first.starting_sector(), first.size_in_sectors());
+        let expected = (Some(2048), Some(2048));
+        assert_eq!(actual, expected);
+
+        let second = disk.get_partition(1)?;
+        let actual = (second.starting_sector(), second.size_in_sectors());
+        let expected = (Some(4096), Some(4096));
+        assert_eq!(actual, expected);
+
+        // Apply only the script headers.
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+        disk.script_apply_headers(&script)?;
+
+        let actual = disk.partition_table_is_of_type(PartitionTableKind::GPT);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_table_id();
+        let expected = Some(String::from("DD27F98D-7519-4C9E-8041-F2BFA7B1EF61"));
+        assert_eq!(actual, expected);
+
+        let actual = disk.used_partitions()?.len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_associate_a_script() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let reader = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let mut script = Script::new(&reader)?;
+        script.add_header("label", "gpt")?;
+
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.script().is_none();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        disk.script_associate(&script)?;
+        drop(script);
+
+        let actual = disk
+            .script()
+            .and_then(|script| script.header_value("label"));
+        let expected = Some("gpt");
+        assert_eq!(actual, expected);
+
+        disk.script_dissociate()?;
+
+        let actual = disk.script().is_none();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_answer_prompts_with_a_callback() -> crate::Result<()> {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let questions = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&questions);
+        disk.set_prompt_callback(move |prompt| {
+            if prompt.kind() == PromptKind::YesNo {
+                counter.set(counter.get() + 1);
+                prompt
+                    .yes_no_set_answer(true)
+                    .map_err(|e| FdiskError::Prompt(e.to_string()))
+            } else {
+                Ok(())
+            }
+        })?;
+
+        let actual = disk.ask_yes_no_question("Proceed?")?;
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        // Verifying the partition table temporarily replaces the callback.
+        disk.verify_partition_table()?;
+
+        let actual = disk.ask_yes_no_question("Proceed again?")?;
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = questions.get();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        disk.unset_prompt_callback()?;
+
+        let result = disk.ask_yes_no_question("Still there?");
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_survive_a_panicking_prompt_callback() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        disk.set_prompt_callback(|_| panic!("prompt callback panicked"))?;
+
+        let result = disk.ask_yes_no_question("Proceed?");
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_find_the_next_free_partition_slot() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.next_free_slot()?;
+        let expected = Some((4, 8192, 12255));
+        assert_eq!(actual, expected);
+
+        disk.partition_delete(1)?;
+
+        // The lowest unused number is reused, the largest free region is unchanged.
+        let actual = disk.next_free_slot()?;
+        let expected = Some((1, 8192, 12255));
+        assert_eq!(actual, expected);
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.next_free_slot();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_find_the_next_free_logical_partition_slot() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .wipe_device_metadata()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+
+        // Extended partition
+        let partition_type = PartitionKind::builder()
+            .code(Code::ExtendedPartition)
+            .build()?;
+        let extended = Partition::builder()
+            .partition_type(partition_type)
+            .number(0)
+            .size_in_sectors(12_288)
+            .build()?;
+        disk.partition_add(extended)?;
+
+        // Primary partitions 1, 2, 3, filling the partition table.
+        for number in 1..4 {
+            let partition_type = PartitionKind::builder().code(Code::Linux).build()?;
+            let primary = Partition::builder()
+                .partition_type(partition_type)
+                .number(number)
+                .size_in_sectors(2_048)
+                .build()?;
+            disk.partition_add(primary)?;
+        }
+
+        // Only logical partitions are left.
+        let actual = disk.next_free_slot()?.map(|(number, _, _)| number);
+        let expected = Some(4);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_a_partition_table_item() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let item = disk.partition_table_item(HeaderEntry::GptDiskGuid)?;
+
+        let actual = item.data_string().map(str::to_owned);
+        let expected = disk.partition_table_id();
+        assert_eq!(actual, expected);
+
+        let item = disk.partition_table_item(HeaderEntry::GptFirstUsableLba)?;
+
+        let actual = item.data_u64();
+        let expected = Some(34);
+        assert_eq!(actual, expected);
+
+        let result = disk.partition_table_item(HeaderEntry::SunRpm);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        let actual = HeaderEntry::SunRpm.partition_table_kind();
+        let expected = Some(PartitionTableKind::SUN);
+        assert_eq!(actual, expected);
+
+        let actual = HeaderEntry::GenericId.partition_table_kind();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_test_the_partition_table_type_of_a_blank_device() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.partition_table_is_of_type(PartitionTableKind::GPT);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let actual = disk.partition_table_is_of_type(PartitionTableKind::GPT);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_table_is_of_type(PartitionTableKind::DOS);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_rereading_changed_entries_of_an_image_file_is_a_no_op() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let entries_on_disk = disk.used_partitions()?;
+
+        disk.partition_delete(3)?;
+        disk.partition_table_write_to_disk()?;
+
+        let actual = disk.reread_changed_partition_entries(&entries_on_disk);
+        let expected = Ok(());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_read_hybrid_mbr_entries() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.gpt_hybrid_mbr_entries()?.len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        // Turn the Protective MBR into a hybrid MBR shadowing the second GPT partition.
+        let mut tmp_image = disk_image_with_pt("gpt");
+        write_mbr_entries(
+            tmp_image.as_file_mut(),
+            &[(0xee, 1, 2_047), (0x0c, 2_048, 2_048)],
+        );
+
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk.gpt_is_hybrid();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let entries = disk.gpt_hybrid_mbr_entries()?;
+
+        let actual = entries.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let entry = &entries[0];
+        let actual = (
+            entry.number(),
+            entry.starting_sector(),
+            entry.size_in_sectors(),
+            entry.partition_type().and_then(|kind| kind.code()),
+        );
+        let expected = (Some(1), Some(2048), Some(2048), Some(0x0c));
+        assert_eq!(actual, expected);
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.gpt_hybrid_mbr_entries();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_sort_partitions_by_starting_sector() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.partition_table_sort_partitions()?;
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        // Reuse partition number 0 for a partition after all the others.
+        disk.partition_delete(0)?;
+        let partition = Partition::builder()
+            .number(0)
+            .starting_sector(8192)
+            .size_in_sectors(2048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let actual = disk.used_partitions()?.is_not_in_increasing_order();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_table_sort_partitions()?;
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.used_partitions()?.is_not_in_increasing_order();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let actual = disk.get_partition(3)?.starting_sector();
+        let expected = Some(8192);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_reserve_sectors_at_the_end_of_a_device() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+        disk.set_reserved_tail_sectors(4096)?;
+
+        let actual = disk.device_last_lba();
+        let expected = 20480 - 4096 - 1;
+        assert_eq!(actual, expected);
+
+        let partition = Partition::builder().build()?;
+        let partition_number = disk.partition_add(partition)?;
+
+        let actual = disk
+            .get_partition(partition_number)?
+            .ending_sector()
+            .map(|end| end <= 20480 - 4096 - 1);
+        let expected = Some(true);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_not_reserve_sectors_overlapping_a_partition() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let last_lba = disk.device_last_lba();
+
+        // Partition 3 ends at sector 8191.
+        let result = disk.set_reserved_tail_sectors(20480 - 8191);
+        assert!(matches!(result, Err(FdiskError::ResultOutOfRange(_))));
+
+        let result = disk.set_reserved_tail_sectors(20480);
+        assert!(matches!(result, Err(FdiskError::ResultOutOfRange(_))));
+
+        let actual = disk.device_last_lba();
+        let expected = last_lba;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_locate_a_partition_table_section_by_name() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let section = disk.partition_table_section_by_name("GPT Header")?.unwrap();
+
+        let actual = section.starting_offset();
+        let expected = 512;
+        assert_eq!(actual, expected);
+
+        let actual = section.size();
+        let expected = 512;
+        assert_eq!(actual, expected);
+
+        let section = disk
+            .partition_table_section_by_name("GPT Backup Header")?
+            .unwrap();
+
+        let actual = section.starting_offset();
+        let expected = (20480 - 1) * 512;
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_table_section_by_name("No Such Section")?;
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_not_locate_a_section_without_a_partition_table() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let result = disk.partition_table_section_by_name("GPT Header");
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_report_the_allocated_size_of_a_sparse_image_file() -> crate::Result<()> {
+        let tmp_image = NamedTempFile::new().expect("failed to get new NamedTempFile");
+        tmp_image
+            .as_file()
+            .set_len(20480 * 512)
+            .expect("failed to set image file size");
+
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let actual = disk
+            .image_file_allocated_size()?
+            .map(|size| size < disk.device_size_in_bytes());
+        let expected = Some(true);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_the_maximum_number_of_partitions_of_a_new_partition_table() -> crate::Result<()>
+    {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let actual = disk.partition_table_max_partitions();
+        let expected = 128;
+        assert_eq!(actual, expected);
+
+        // Capacity does not depend on the number of partitions in use.
+        let partition = Partition::builder().size_in_sectors(2048).build()?;
+        disk.partition_add(partition)?;
+
+        let actual = disk.partition_table_max_partitions();
+        let expected = 128;
+        assert_eq!(actual, expected);
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+
+        let actual = disk.partition_table_max_partitions();
+        let expected = 4;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_clear_all_gpt_attribute_bits_of_a_partition() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.gpt_set_attribute_bits(0, 1u64 << 2)?;
+        disk.gpt_set_partition_read_only(0, true)?;
+        disk.gpt_set_partition_hidden(0, true)?;
+
+        disk.gpt_clear_attribute_bits(0)?;
+
+        let actual = disk.gpt_attribute_bits(0);
+        let expected = Some(0);
+        assert_eq!(actual, expected);
+
+        let actual = disk
+            .partition_table_current()
+            .map(|table| table.has_changes());
+        let expected = Some(true);
+        assert_eq!(actual, expected);
+
+        let result = disk.gpt_clear_attribute_bits(200);
+        assert!(matches!(result, Err(FdiskError::NoSuchPartition(_))));
+
+        let tmp_image = disk_image_with_pt("sun");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let result = disk.gpt_clear_attribute_bits(0);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_be_compared_to_another_script() -> crate::Result<()> {
+        let mut old_file = NamedTempFile::new().unwrap();
+        old_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\n/dev/sda1 : start=2048, size=2048, type=L\n/dev/sda2 : start=4096, size=4096, type=L\n/dev/sda3 : start=8192, size=2048, type=L\n",
+            )
+            .unwrap();
+
+        let mut new_file = NamedTempFile::new().unwrap();
+        new_file
+            .write_all(
+                b"label: dos\nunit: sectors\n\n/dev/sda1 : start=2048, size=2048, type=L\n/dev/sda2 : start=4096, size=6144, type=L\n/dev/sda4 : start=12288, size=2048, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let old_script = Script::new_from_file(&disk, old_file.path())?;
+        let new_script = Script::new_from_file(&disk, new_file.path())?;
+
+        let diff = old_script.diff(&new_script);
+
+        let actual: Vec<_> = diff
+            .changed_headers()
+            .iter()
+            .map(|change| (change.name(), change.old_value(), change.new_value()))
+            .collect();
+        let expected = vec![("label", Some("gpt"), Some("dos"))];
+        assert_eq!(actual, expected);
+
+        let actual = diff.added_partitions();
+        let expected = [3];
+        assert_eq!(actual, expected);
+
+        let actual = diff.removed_partitions();
+        let expected = [2];
+        assert_eq!(actual, expected);
+
+        let actual = diff.modified_partitions();
+        let expected = [1];
+        assert_eq!(actual, expected);
+
+        let actual = old_script.diff(&old_script).is_empty();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_be_compared_to_a_script_with_unnumbered_entries() -> crate::Result<()> {
+        // The unnumbered entry gets the first free partition number, i.e. `/dev/sda1`'s.
+        let mut old_file = NamedTempFile::new().unwrap();
+        old_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\n/dev/sda2 : start=4096, size=4096, type=L\nstart=2048, size=2048, type=L\n",
+            )
+            .unwrap();
+
+        let mut new_file = NamedTempFile::new().unwrap();
+        new_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\n/dev/sda1 : start=2048, size=2048, type=L\n/dev/sda2 : start=4096, size=4096, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let old_script = Script::new_from_file(&disk, old_file.path())?;
+        let new_script = Script::new_from_file(&disk, new_file.path())?;
+
+        let actual = old_script.diff(&new_script).is_empty();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_list_supported_partition_table_types() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let kinds = disk.supported_labels();
+
+        let actual = kinds.len();
+        let expected = disk.partition_table_count_types();
+        assert_eq!(actual, expected);
+
+        let actual = kinds.contains(&PartitionTableKind::GPT);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = kinds.contains(&PartitionTableKind::DOS);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_the_current_partition_table() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.partition_table_current().map(|table| table.kind());
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let actual = disk.partition_table_current().map(|table| table.kind());
+        let expected = Some(PartitionTableKind::GPT);
+        assert_eq!(actual, expected);
+
+        disk.partition_table_create(PartitionTableKind::DOS)?;
+
+        let actual = disk.partition_table_current().map(|table| table.kind());
+        let expected = Some(PartitionTableKind::DOS);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_add_a_partition_aligned_on_a_custom_boundary() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        // Align on a 4 MiB boundary, i.e. every 8192 sectors.
+        let partition = Partition::builder().size_in_sectors(2048).build()?;
+        let partition_number =
+            disk.partition_add_aligned(partition, AlignmentTarget::Bytes(4 * 1024 * 1024))?;
+
+        let actual = disk.get_partition(partition_number)?.starting_sector();
+        let expected = Some(8192);
+        assert_eq!(actual, expected);
+
+        // An explicit starting sector is rounded up to the next boundary.
+        let partition = Partition::builder()
+            .starting_sector(10_241)
+            .size_in_sectors(2048)
+            .build()?;
+        let partition_number = disk.partition_add_aligned(partition, AlignmentTarget::Grain)?;
+
+        let actual = disk.get_partition(partition_number)?.starting_sector();
+        let expected = Some(12_288);
+        assert_eq!(actual, expected);
+
+        // No free region is large enough.
+        let partition = Partition::builder().size_in_sectors(u64::MAX / 2).build()?;
+        let result = disk.partition_add_aligned(partition, AlignmentTarget::Grain);
+        assert!(matches!(result, Err(FdiskError::ResultOutOfRange(_))));
+
+        // Without a size, the partition starts on the first boundary inside a free region.
+        let partition = Partition::builder().build()?;
+        let partition_number =
+            disk.partition_add_aligned(partition, AlignmentTarget::Bytes(4 * 1024 * 1024))?;
+
+        let actual = disk.get_partition(partition_number)?.starting_sector();
+        let expected = Some(16_384);
+        assert_eq!(actual, expected);
+
+        let partition = Partition::builder().size_in_sectors(2048).build()?;
+        let result = disk.partition_add_aligned(partition, AlignmentTarget::Bytes(256));
+        assert!(matches!(result, Err(FdiskError::DataAlignment(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_compute_the_number_of_sectors_taken_up_by_gpt_structures() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let actual = disk.gpt_overhead_sectors()?;
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        // PMBR, then a Header and 128 entries * 128 bytes / 512 bytes per sector for each of the
+        // primary and backup tables.
+        let actual = disk.gpt_overhead_sectors()?;
+        let expected = Some(1 + 2 * (1 + 32));
+        assert_eq!(actual, expected);
+
+        disk.gpt_set_partition_entry_array_size(256)?;
+
+        let actual = disk.gpt_overhead_sectors()?;
+        let expected = Some(1 + 2 * (1 + 64));
+        assert_eq!(actual, expected);
+
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .device_sector_sizes(4096, 4096)
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let actual = disk.gpt_overhead_sectors()?;
+        let expected = Some(1 + 2 * (1 + 4));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_collect_partition_table_field_identifiers() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let table = disk.partition_table_current().unwrap();
+
+        let default_fields = disk.partition_table_collect_partition_fields(table)?;
+
+        let actual = default_fields.contains(&Field::StartingLba);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = default_fields.contains(&Field::Size);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let all_fields = disk.partition_table_collect_all_partition_fields(table)?;
+
+        let actual = default_fields
+            .iter()
+            .all(|field| all_fields.contains(field));
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = all_fields.contains(&Field::AttributeBits);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_be_simulated_on_a_virtual_device() -> crate::Result<()> {
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\nstart=2048, size=2048, type=L\nstart=4096, size=4096, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let script = Script::new_from_file(&disk, script_file.path())?;
+
+        let partitions = script.simulate(20480, 512).unwrap();
+
+        let actual = partitions.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        let actual = partitions
+            .get(1)
+            .and_then(|partition| partition.starting_sector());
+        let expected = Some(4096);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn script_can_not_be_simulated_on_a_virtual_device_too_small() -> crate::Result<()> {
+        let mut script_file = NamedTempFile::new().unwrap();
+        script_file
+            .write_all(
+                b"label: gpt\nunit: sectors\n\nstart=2048, size=2048, type=L\nstart=4096, size=40960, type=L\n",
+            )
+            .unwrap();
+
+        let tmp_image = blank_image_file();
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+        let script = Script::new_from_file(&disk, script_file.path())?;
+
+        let result = script.simulate(20480, 512);
+        assert!(result.is_err());
+
+        let actual = result.err().map(|problems| problems.is_empty());
+        let expected = Some(false);
+        assert_eq!(actual, expected);
+
+        let result = script.simulate(u64::MAX, 512);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_convert_a_partition_field_to_string() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .partition_size_format(SizeFormat::HumanReadable)
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+        let partition = Partition::builder()
+            .starting_sector(2048)
+            .size_in_sectors(4096)
+            .build()?;
+        let partition_number = disk.partition_add(partition)?;
+        let partition = disk.get_partition(partition_number)?;
+
+        let actual = disk.partition_field_to_string(Field::Size, &partition)?;
+        let expected = "2M";
+        assert_eq!(actual, expected);
+
+        let actual = disk.partition_field_to_string(Field::StartingLba, &partition)?;
+        let expected = "2048";
+        assert_eq!(actual, expected);
+
+        disk.set_partition_size_format(SizeFormat::Bytes)?;
+
+        let actual = disk.partition_field_to_string(Field::Size, &partition)?;
+        let expected = "2097152";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_compact_partition_numbers() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        // Name, UUID, and attribute bits of each partition.
+        let details = |disk: &Fdisk| -> crate::Result<Vec<_>> {
+            let details = disk
+                .used_partitions()?
+                .iter()
+                .map(|partition| {
+                    (
+                        partition.name().map(str::to_owned),
+                        partition.uuid().map(str::to_owned),
+                        partition.attribute_bits(),
+                    )
+                })
+                .collect();
+
+            Ok(details)
+        };
+
+        disk.partition_delete(1)?;
+        let before = details(&disk)?;
+
+        disk.compact_partition_numbers()?;
+
+        let actual: Vec<_> = disk
+            .used_partitions()?
+            .iter()
+            .map(|partition| (partition.number(), partition.starting_sector()))
+            .collect();
+        let expected = vec![
+            (Some(0), Some(34)),
+            (Some(1), Some(4096)),
+            (Some(2), Some(6144)),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = details(&disk)?;
+        let expected = before;
+        assert_eq!(actual, expected);
+
+        let actual = disk.has_unsaved_changes();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let tmp_image = disk_image_with_pt("sun");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let result = disk.compact_partition_numbers();
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_get_the_next_free_partition_number() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let template = Partition::builder().build()?;
+
+        let actual = disk.next_free_partition_number(&template)?;
+        let expected = Some(4);
+        assert_eq!(actual, expected);
+
+        disk.partition_delete(1)?;
+        disk.partition_delete(3)?;
+
+        let actual = disk.next_free_partition_number(&template)?;
+        let expected = Some(1);
+        assert_eq!(actual, expected);
+
+        let template = Partition::builder().number(3).build()?;
+
+        let actual = disk.next_free_partition_number(&template)?;
+        let expected = Some(3);
+        assert_eq!(actual, expected);
+
+        let template = Partition::builder().number(0).build()?;
+
+        let result = disk.next_free_partition_number(&template);
+        assert!(matches!(result, Err(FdiskError::ResultOutOfRange(_))));
+
+        // Shrink the Partition Entry Array to the number of partitions in use.
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+        disk.gpt_set_partition_entry_array_size(4)?;
+
+        let template = Partition::builder().build()?;
+
+        let actual = disk.next_free_partition_number(&template)?;
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_add_a_partition_and_wipe_its_signatures() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition = Partition::builder()
+            .starting_sector(2048)
+            .size_in_sectors(2048)
+            .build()?;
+        let partition_number = disk.partition_add_wiping_signatures(partition)?;
+        let partition = disk.get_partition(partition_number)?;
+
+        let actual = disk.is_partition_wipe_active(&partition);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let partition = Partition::builder()
+            .starting_sector(4096)
+            .size_in_sectors(2048)
+            .build()?;
+        let partition_number = disk.partition_add(partition)?;
+        let partition = disk.get_partition(partition_number)?;
+
+        let actual = disk.is_partition_wipe_active(&partition);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_toggle_the_wipe_of_a_partition_region() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_wipe_activate(1)?;
+        let partition = disk.get_partition(1)?;
+
+        let actual = disk.is_partition_wipe_active(&partition);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let partition = disk.get_partition(2)?;
+
+        let actual = disk.is_partition_wipe_active(&partition);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        disk.partition_wipe_deactivate(1)?;
+        let partition = disk.get_partition(1)?;
+
+        let actual = disk.is_partition_wipe_active(&partition);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_check_whether_a_partition_slot_is_used() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .enable_read_write()
+            .build()?;
+
+        let result = disk.is_partition_slot_used(0);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition = Partition::builder()
+            .number(0)
+            .starting_sector(2048)
+            .size_in_sectors(2048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let actual = disk.is_partition_slot_used(0)?;
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = disk.is_partition_slot_used(1)?;
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_report_partition_alignment() -> crate::Result<()> {
+        let tmp_image = blank_image_file();
+        let mut disk = Fdisk::builder()
+            .assign_device(tmp_image.path())
+            .device_sector_sizes(4096, 512)
+            .enable_read_write()
+            .build()?;
+
+        disk.partition_table_create(PartitionTableKind::GPT)?;
+
+        let partition = Partition::builder()
+            .starting_sector(2048)
+            .size_in_sectors(2048)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        // Deliberately misaligned partition.
+        let partition = Partition::builder()
+            .starting_sector(4097)
+            .size_in_sectors(2047)
+            .build()?;
+        disk.partition_add(partition)?;
+
+        let actual = disk.partition_alignment_report()?;
+        let expected = vec![(0, true), (1, false)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fdisk_can_export_and_import_a_disk_layout() -> crate::Result<()> {
+        let tmp_image = disk_image_with_pt("gpt");
+        let disk = Fdisk::builder().assign_device(tmp_image.path()).build()?;
+
+        let layout = disk.export_layout()?;
+
+        let actual = layout.label_kind();
+        let expected = PartitionTableKind::GPT;
+        assert_eq!(actual, expected);
+
+        let actual = layout.partitions().len();
+        let expected = 4;
+        assert_eq!(actual, expected);
+
+        let blank_image = blank_image_file();
+        let mut copy = Fdisk::builder()
+            .assign_device(blank_image.path())
+            .enable_read_write()
+            .build()?;
+
+        copy.import_layout(&layout)?;
+
+        let actual = copy.export_layout()?;
+        let expected = layout.clone();
+        assert_eq!(actual, expected);
+
+        // An invalid layout leaves the current partition table untouched.
+        let invalid = DiskLayout::new(
+            PartitionTableKind::GPT,
+            Some("not-a-guid".to_owned()),
+            layout.sector_size(),
+            Vec::new(),
+        );
+        let result = copy.import_layout(&invalid);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        let actual = copy.export_layout()?;
+        let expected = layout.clone();
+        assert_eq!(actual, expected);
+
+        let blank_image = blank_image_file();
+        let mut copy = Fdisk::builder()
+            .assign_device(blank_image.path())
+            .device_sector_sizes(4096, 4096)
+            .enable_read_write()
+            .build()?;
+
+        let result = copy.import_layout(&layout);
+        assert!(matches!(result, Err(FdiskError::Config(_))));
+
+        Ok(())
+    }
 }
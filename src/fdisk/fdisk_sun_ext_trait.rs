@@ -10,6 +10,10 @@
 use crate::fdisk::Fdisk;
 use crate::fdisk::FdiskError;
 
+use crate::core::partition_table::HeaderEntry;
+use crate::core::partition_table::HeaderEntryContent;
+use crate::core::partition_table::PartitionTableKind;
+
 /// `SUN` specific functions for a [`Fdisk`].
 ///
 /// This trait is sealed and can not be implemented for types outside of `rsfdisk`.
@@ -33,6 +37,62 @@ pub trait FdiskSUNExt: Sealed {
     /// Sets the assigned device's number of extra sectors per cylinder by
     /// [`Prompt`](crate::core::prompt::Prompt)ing the user for a value.
     fn sun_set_extra_sectors_per_cylinder(&mut self) -> Result<(), FdiskError>;
+
+    /// Returns the assigned device's number of alternate cylinders.
+    fn sun_alternate_cylinder_count(&self) -> Result<u64, FdiskError>;
+
+    /// Returns the assigned device's interleave factor.
+    fn sun_interleave_factor(&self) -> Result<u64, FdiskError>;
+
+    /// Returns the assigned device's number of physical cylinders.
+    fn sun_physical_cylinder_count(&self) -> Result<u64, FdiskError>;
+
+    /// Returns the assigned device's rotation speed.
+    fn sun_rotation_per_minute(&self) -> Result<u64, FdiskError>;
+
+    /// Returns the assigned device's number of extra sectors per cylinder.
+    fn sun_extra_sectors_per_cylinder(&self) -> Result<u64, FdiskError>;
+
+    /// Returns the volume ID of the `SUN` partition table, or `None` if it is not set.
+    fn sun_volume_id(&self) -> Result<Option<String>, FdiskError>;
+}
+
+/// Returns the content of a `SUN` partition table header entry, or an error if the assigned
+/// device's partition table is not a `SUN` partition table.
+fn sun_header_entry(
+    fdisk: &Fdisk,
+    header_entry: HeaderEntry,
+) -> Result<HeaderEntryContent, FdiskError> {
+    if !fdisk.partition_table_is_of_type(PartitionTableKind::SUN) {
+        let err_msg = format!(
+            "failed to get {:?}. No SUN partition table on device",
+            header_entry
+        );
+        log::debug!("FdiskSUNExt::sun_header_entry {}", err_msg);
+
+        return Err(FdiskError::Config(err_msg));
+    }
+
+    fdisk
+        .partition_table_header_entry(header_entry)
+        .ok_or_else(|| {
+            let err_msg = format!("failed to get {:?}", header_entry);
+            log::debug!("FdiskSUNExt::sun_header_entry {}", err_msg);
+
+            FdiskError::Config(err_msg)
+        })
+}
+
+/// Returns the numeric value of a `SUN` partition table header entry.
+fn sun_header_entry_u64(fdisk: &Fdisk, header_entry: HeaderEntry) -> Result<u64, FdiskError> {
+    let content = sun_header_entry(fdisk, header_entry)?;
+
+    content.data_u64().ok_or_else(|| {
+        let err_msg = format!("no numeric value for {:?}", header_entry);
+        log::debug!("FdiskSUNExt::sun_header_entry_u64 {}", err_msg);
+
+        FdiskError::Unexpected(err_msg)
+    })
 }
 
 impl<'a> FdiskSUNExt for Fdisk<'a> {
@@ -145,4 +205,48 @@ fn sun_set_extra_sectors_per_cylinder(&mut self) -> Result<(), FdiskError> {
             }
         }
     }
+
+    fn sun_alternate_cylinder_count(&self) -> Result<u64, FdiskError> {
+        log::debug!(
+            "Fdisk::sun_alternate_cylinder_count getting SUN number of alternate cylinders"
+        );
+
+        sun_header_entry_u64(self, HeaderEntry::SunAlternateCylinders)
+    }
+
+    fn sun_interleave_factor(&self) -> Result<u64, FdiskError> {
+        log::debug!("Fdisk::sun_interleave_factor getting SUN interleave factor");
+
+        sun_header_entry_u64(self, HeaderEntry::SunInterleave)
+    }
+
+    fn sun_physical_cylinder_count(&self) -> Result<u64, FdiskError> {
+        log::debug!("Fdisk::sun_physical_cylinder_count getting SUN number of physical cylinders");
+
+        sun_header_entry_u64(self, HeaderEntry::SunPhysicalCylinders)
+    }
+
+    fn sun_rotation_per_minute(&self) -> Result<u64, FdiskError> {
+        log::debug!("Fdisk::sun_rotation_per_minute getting SUN rotation speed");
+
+        sun_header_entry_u64(self, HeaderEntry::SunRpm)
+    }
+
+    fn sun_extra_sectors_per_cylinder(&self) -> Result<u64, FdiskError> {
+        log::debug!(
+            "Fdisk::sun_extra_sectors_per_cylinder getting SUN number of extra sectors per cylinder"
+        );
+
+        sun_header_entry_u64(self, HeaderEntry::SunExtraSectorsPerCylinder)
+    }
+
+    fn sun_volume_id(&self) -> Result<Option<String>, FdiskError> {
+        log::debug!("Fdisk::sun_volume_id getting SUN volume ID");
+
+        let content = sun_header_entry(self, HeaderEntry::SunVolumeId)?;
+        let volume_id = content.data_string().map(str::to_owned);
+        log::debug!("Fdisk::sun_volume_id value: {:?}", volume_id);
+
+        Ok(volume_id)
+    }
 }
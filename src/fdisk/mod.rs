@@ -231,6 +231,7 @@
 
 // From this library
 
+pub use alignment_target_enum::AlignmentTarget;
 pub use device_addressing_enum::DeviceAddressing;
 pub use fdisk_bsd_ext_trait::FdiskBSDExt;
 pub use fdisk_builder_error_enum::FdiskBuilderError;
@@ -244,10 +245,13 @@
 pub use fdisk_sun_ext_trait::FdiskSUNExt;
 pub(crate) use gc_item_enum::GcItem;
 pub(crate) use lba_align_enum::LBAAlign;
+pub(crate) use partition_layout_struct::PartitionLayout;
 pub use partition_table_iter_mut_struct::PartitionTableIterMut;
 pub use partition_table_iter_struct::PartitionTableIter;
 pub use size_format_enum::SizeFormat;
+pub use write_stage_enum::WriteStage;
 
+mod alignment_target_enum;
 mod device_addressing_enum;
 mod fdisk_bsd_ext_trait;
 mod fdisk_builder_error_enum;
@@ -260,6 +264,8 @@
 mod fdisk_sun_ext_trait;
 mod gc_item_enum;
 mod lba_align_enum;
+mod partition_layout_struct;
 mod partition_table_iter_mut_struct;
 mod partition_table_iter_struct;
 mod size_format_enum;
+mod write_stage_enum;
@@ -0,0 +1,35 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::Partition;
+
+/// Characteristics of a partition compared by [`Fdisk::verify_written`](crate::fdisk::Fdisk::verify_written).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct PartitionLayout {
+    number: Option<usize>,
+    starting_sector: Option<u64>,
+    size_in_sectors: Option<u64>,
+    partition_type: Option<String>,
+    name: Option<String>,
+    uuid: Option<String>,
+}
+
+impl From<&Partition> for PartitionLayout {
+    fn from(partition: &Partition) -> PartitionLayout {
+        Self {
+            number: partition.number(),
+            starting_sector: partition.starting_sector(),
+            size_in_sectors: partition.size_in_sectors(),
+            partition_type: partition
+                .partition_type()
+                .and_then(|kind| kind.type_identifier()),
+            name: partition.name().map(str::to_owned),
+            uuid: partition.uuid().map(str::to_lowercase),
+        }
+    }
+}
@@ -15,6 +15,8 @@
 #[derive(Debug)]
 pub struct PartitionTableIterMut<'a> {
     partitioner: &'a mut Fdisk<'a>,
+    /// Current item in iteration.
+    cursor: *mut libfdisk::fdisk_label,
 }
 
 impl<'a> PartitionTableIterMut<'a> {
@@ -22,7 +24,10 @@ impl<'a> PartitionTableIterMut<'a> {
     pub(crate) fn new(partitioner: &'a mut Fdisk<'a>) -> PartitionTableIterMut<'a> {
         log::debug!("PartitionTableIterMut::new creating new `PartitionTableIterMut` instance");
 
-        Self { partitioner }
+        Self {
+            partitioner,
+            cursor: std::ptr::null_mut(),
+        }
     }
 }
 
@@ -32,7 +37,9 @@ impl<'a> Iterator for PartitionTableIterMut<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         log::debug!("PartitionTableIterMut::next getting next partition table");
 
-        let mut table_ptr = MaybeUninit::<*mut libfdisk::fdisk_label>::zeroed();
+        // `libfdisk::fdisk_next_label` reads the current item from its second argument, then
+        // replaces it with the next one.
+        let mut table_ptr = MaybeUninit::<*mut libfdisk::fdisk_label>::new(self.cursor);
 
         let result =
             unsafe { libfdisk::fdisk_next_label(self.partitioner.inner, table_ptr.as_mut_ptr()) };
@@ -42,6 +49,7 @@ fn next(&mut self) -> Option<Self::Item> {
                 log::debug!("PartitionTableIterMut::next got next partition table");
 
                 let ptr = unsafe { table_ptr.assume_init() };
+                self.cursor = ptr;
                 let table = owning_mut_from_ptr!(self.partitioner, PartitionTable, ptr);
 
                 Some(table)
@@ -15,13 +15,18 @@
 #[derive(Debug)]
 pub struct PartitionTableIter<'a> {
     partitioner: &'a Fdisk<'a>,
+    /// Current item in iteration.
+    cursor: *mut libfdisk::fdisk_label,
 }
 
 impl<'a> PartitionTableIter<'a> {
     pub(crate) fn new(partitioner: &'a Fdisk) -> PartitionTableIter<'a> {
         log::debug!("PartitionTableIter::new creating new `PartitionTableIter` instance");
 
-        Self { partitioner }
+        Self {
+            partitioner,
+            cursor: std::ptr::null_mut(),
+        }
     }
 }
 
@@ -31,7 +36,9 @@ impl<'a> Iterator for PartitionTableIter<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         log::debug!("PartitionTableIter::next getting next partition table");
 
-        let mut table_ptr = MaybeUninit::<*mut libfdisk::fdisk_label>::zeroed();
+        // `libfdisk::fdisk_next_label` reads the current item from its second argument, then
+        // replaces it with the next one.
+        let mut table_ptr = MaybeUninit::<*mut libfdisk::fdisk_label>::new(self.cursor);
 
         let result =
             unsafe { libfdisk::fdisk_next_label(self.partitioner.inner, table_ptr.as_mut_ptr()) };
@@ -41,6 +48,7 @@ fn next(&mut self) -> Option<Self::Item> {
                 log::debug!("PartitionTableIter::next got next partition table");
 
                 let ptr = unsafe { table_ptr.assume_init() };
+                self.cursor = ptr;
                 let table = owning_ref_from_ptr!(self.partitioner, PartitionTable, ptr);
 
                 Some(table)
@@ -0,0 +1,26 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// Milestones reported while writing a partition table to disk.
+///
+/// `libfdisk` writes a partition table in a single operation, without reporting on its
+/// progress. Thus, a write is reported when it starts, once it is done, and once the partition
+/// table read back from disk is confirmed to match the in-memory copy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum WriteStage {
+    /// Writing is about to start.
+    Started,
+
+    /// `libfdisk` wrote the partition table to disk.
+    Written,
+
+    /// The partition table read back from disk matches the in-memory copy.
+    Verified,
+}
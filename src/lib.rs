@@ -97,7 +97,7 @@
 //! | [`fdisk_get_devmodel`][15]              | [`Fdisk::device_model`](crate::fdisk::Fdisk::device_model)                                                                                                                                       |
 //! | [`fdisk_get_devname`][16]               | [`Fdisk::device_name`](crate::fdisk::Fdisk::device_name)                                                                                                                                         |
 //! | [`fdisk_get_devno`][17]                 | [`Fdisk::device_number`](crate::fdisk::Fdisk::device_number)                                                                                                                                     |
-//! | [`fdisk_get_disklabel_item`][18]        | [`Fdisk::partition_table_header_entry`](crate::fdisk::Fdisk::partition_table_header_entry)                                                                                                       |
+//! | [`fdisk_get_disklabel_item`][18]        | [`Fdisk::partition_table_item`](crate::fdisk::Fdisk::partition_table_item)<br>[`Fdisk::partition_table_header_entry`](crate::fdisk::Fdisk::partition_table_header_entry)                                  |
 //! | [`fdisk_get_first_lba`][19]             | [`Fdisk::device_first_lba`](crate::fdisk::Fdisk::device_first_lba)                                                                                                                               |
 //! | [`fdisk_get_geom_cylinders`][20]        | [`Fdisk::device_count_cylinders`](crate::fdisk::Fdisk::device_count_cylinders)                                                                                                                   |
 //! | [`fdisk_get_geom_heads`][21]            | [`Fdisk::device_count_heads`](crate::fdisk::Fdisk::device_count_heads)                                                                                                                           |
@@ -124,7 +124,7 @@
 //! | [`fdisk_is_readonly`][42]               | [`Fdisk::device_is_read_only`](crate::fdisk::Fdisk::device_is_read_only)                                                                                                                         |
 //! | [`fdisk_is_regfile`][43]                | [`Fdisk::device_is_image_file`](crate::fdisk::Fdisk::device_is_image_file)                                                                                                                       |
 //! | [`fdisk_new_context`][44]               | [`Fdisk::builder`](crate::fdisk::Fdisk::builder)                                                                                                                                                 |
-//! | [`fdisk_new_nested_context`][45]        | [`Fdisk::create_nested_partitioner`](crate::fdisk::Fdisk::create_nested_partitioner)<br>[`Fdisk::create_nested_partitioner_with_name`](crate::fdisk::Fdisk::create_nested_partitioner_with_name) |
+//! | [`fdisk_new_nested_context`][45]        | [`Fdisk::create_nested_partitioner`](crate::fdisk::Fdisk::create_nested_partitioner)<br>[`Fdisk::create_nested_partitioner_with_name`](crate::fdisk::Fdisk::create_nested_partitioner_with_name)<br>[`Fdisk::create_nested_partitioner_for`](crate::fdisk::Fdisk::create_nested_partitioner_for) |
 //! | [`fdisk_ref_context`][46]               | Managed automatically.                                                                                                                                                                           |
 //! | [`fdisk_reread_changes`][47]            | [`Fdisk::reread_changed_partition_entries`](crate::fdisk::Fdisk::reread_changed_partition_entries)                                                                                               |
 //! | [`fdisk_reread_partition_table`][48]    | [`Fdisk::reread_partition_entries`](crate::fdisk::Fdisk::reread_partition_entries)                                                                                                               |
@@ -199,7 +199,7 @@
 //! | [`fdisk_info`][57]                        | [`Fdisk::log_info`](crate::fdisk::Fdisk::log_info)                                                                                                                                   |
 //! | [`fdisk_warn`][58]                        | [`Fdisk::log_warn_set_errno`](crate::fdisk::Fdisk::log_warn_set_errno)                                                                                                               |
 //! | [`fdisk_warnx`][59]                       | [`Fdisk::log_warn`](crate::fdisk::Fdisk::log_warn)                                                                                                                                   |
-//! | [`fdisk_set_ask`][60]                     | TBD                                                                                                                                                                                  |
+//! | [`fdisk_set_ask`][60]                     | [`Fdisk::set_prompt_callback`](crate::fdisk::Fdisk::set_prompt_callback)<br>[`Fdisk::unset_prompt_callback`](crate::fdisk::Fdisk::unset_prompt_callback)                                     |
 //! | [`fdisk_is_ask`][61]                      | [`Prompt::is_of_kind`](crate::core::prompt::Prompt::is_of_kind)                                                                                                                      |
 //! | [`fdisk_ask_get_query`][62]               | [`Prompt::query`](crate::core::prompt::Prompt::query)                                                                                                                                |
 //! | [`fdisk_ask_get_type`][63]                | [`Prompt::kind`](crate::core::prompt::Prompt::kind)                                                                                                                                  |
@@ -303,12 +303,12 @@
 //! | `libfdisk`                            | `rsfdisk`                                                                                                                                                            |
 //! | ------------------                    | ---------                                                                                                                                                            |
 //! | [`struct fdisk_script`][103]          | [`Script`](crate::core::script::Script)                                                                                                                              |
-//! | [`fdisk_set_script`][104]             | [`Fdisk::script_dissociate`](crate::fdisk::Fdisk::script_dissociate)                                                                                                 |
+//! | [`fdisk_set_script`][104]             | [`Fdisk::script_associate`](crate::fdisk::Fdisk::script_associate)<br>[`Fdisk::script_dissociate`](crate::fdisk::Fdisk::script_dissociate)                                     |
 //! | [`fdisk_get_script`][105]             | [`Fdisk::script`](crate::fdisk::Fdisk::script) <br> [`Fdisk::script_mut`](crate::fdisk::Fdisk::script_mut)                                                           |
 //! | [`fdisk_apply_script`][106]           | [`Fdisk::script_apply`](crate::fdisk::Fdisk::script_apply)                                                                                                           |
 //! | [`fdisk_apply_script_headers`][107]   | [`Fdisk::script_apply_headers`](crate::fdisk::Fdisk::script_apply_headers)                                                                                           |
-//! | [`fdisk_new_script`][108]             | [`Fdisk::script_new`](crate::fdisk::Fdisk::script_new)                                                                                                               |
-//! | [`fdisk_new_script_from_file`][109]   | [`Fdisk::script_new_from_file`](crate::fdisk::Fdisk::script_new_from_file)                                                                                           |
+//! | [`fdisk_new_script`][108]             | [`Fdisk::script_new`](crate::fdisk::Fdisk::script_new)<br>[`Script::new`](crate::core::script::Script::new)                                                                    |
+//! | [`fdisk_new_script_from_file`][109]   | [`Fdisk::script_new_from_file`](crate::fdisk::Fdisk::script_new_from_file)<br>[`Script::new_from_file`](crate::core::script::Script::new_from_file)                            |
 //! | [`fdisk_ref_script`][110]             | Managed automatically.                                                                                                                                               |
 //! | [`fdisk_script_enable_json`][111]     | [`Script::enable_json_output`](crate::core::script::Script::enable_json_output)<br>[`Script::disable_json_output`](crate::core::script::Script::disable_json_output) |
 //! | [`fdisk_script_get_header`][112]      | [`Script::header_value`](crate::core::script::Script::header_value)                                                                                                  |
@@ -359,18 +359,18 @@
 //! | [`enum   fdisk_labeltype`][127]               | [`PartitionTableKind`](crate::core::partition_table::PartitionTableKind)                                                                                                                                                                                                                                                                                     |
 //! | [`fdisk_create_disklabel`][128]               | [`Fdisk::partition_table_create_default`](crate::fdisk::Fdisk::partition_table_create_default)<br>[`Fdisk::partition_table_create`](crate::fdisk::Fdisk::partition_table_create)                                                                                                                                                                             |
 //! | [`fdisk_list_disklabel`][129]                 | [`Fdisk::partition_table_display_details`](crate::fdisk::Fdisk::partition_table_display_details)                                                                                                                                                                                                                                                             |
-//! | [`fdisk_locate_disklabel`][130]               | [`Fdisk::partition_table_section`](crate::fdisk::Fdisk::partition_table_section)                                                                                                                                                                                                                                                                             |
+//! | [`fdisk_locate_disklabel`][130]               | [`Fdisk::partition_table_section`](crate::fdisk::Fdisk::partition_table_section)<br>[`Fdisk::partition_table_section_by_name`](crate::fdisk::Fdisk::partition_table_section_by_name)                                                                                                                                                                         |
 //! | [`fdisk_reorder_partitions`][131]             | [`Fdisk::partition_table_sort_partitions`](crate::fdisk::Fdisk::partition_table_sort_partitions)                                                                                                                                                                                                                                                             |
 //! | [`fdisk_set_disklabel_id`][132]               | [`Fdisk::partition_table_set_id`](crate::fdisk::Fdisk::partition_table_set_id)                                                                                                                                                                                                                                                                               |
-//! | [`fdisk_set_disklabel_id_from_string`][133]   | [`Fdisk::partition_table_set_string_id`](crate::fdisk::Fdisk::partition_table_set_string_id)                                                                                                                                                                                                                                                                 |
+//! | [`fdisk_set_disklabel_id_from_string`][133]   | [`Fdisk::partition_table_set_string_id`](crate::fdisk::Fdisk::partition_table_set_string_id)<br>[`Fdisk::randomize_gpt_disk_guid`](crate::fdisk::Fdisk::randomize_gpt_disk_guid)                                                                                                                                                                             |
 //! | [`fdisk_set_partition_type`][134]             | [`Fdisk::partition_table_set_partition_type`](crate::fdisk::Fdisk::partition_table_set_partition_type)                                                                                                                                                                                                                                                       |
 //! | [`fdisk_toggle_partition_flag`][135]          | [`Fdisk::partition_table_toggle_partition_flag`](crate::fdisk::Fdisk::partition_table_toggle_partition_flag)                                                                                                                                                                                                                                                 |
-//! | [`fdisk_verify_disklabel`][136]               | [`Fdisk::partition_table_check`](crate::fdisk::Fdisk::partition_table_check)                                                                                                                                                                                                                                                                                 |
+//! | [`fdisk_verify_disklabel`][136]               | [`Fdisk::partition_table_check`](crate::fdisk::Fdisk::partition_table_check)<br>[`Fdisk::verify_partition_table`](crate::fdisk::Fdisk::verify_partition_table)                                                                                                                                                                                             |
 //! | [`fdisk_write_disklabel`][137]                | [`Fdisk::partition_table_write_to_disk`](crate::fdisk::Fdisk::partition_table_write_to_disk)                                                                                                                                                                                                                                                                 |
 //! | [`fdisk_get_disklabel_id`][138]               | [`Fdisk::partition_table_id`](crate::fdisk::Fdisk::partition_table_id)                                                                                                                                                                                                                                                                                       |
 //! | [`fdisk_get_label`][139]                      | [`Fdisk::partition_table_current`](crate::fdisk::Fdisk::partition_table_current)<br>[`Fdisk::partition_table_current_mut`](crate::fdisk::Fdisk::partition_table_current_mut)<br>[`Fdisk::partition_table_by_type`](crate::fdisk::Fdisk::partition_table_by_type)<br>[`Fdisk::partition_table_by_type_mut`](crate::fdisk::Fdisk::partition_table_by_type_mut) |
 //! | [`fdisk_get_nlabels`][140]                    | [`Fdisk::partition_table_count_types`](crate::fdisk::Fdisk::partition_table_count_types)                                                                                                                                                                                                                                                                     |
-//! | [`fdisk_next_label`][141]                     | [`Fdisk::iter`](crate::fdisk::Fdisk::iter) <br> [`Fdisk::iter_mut`](crate::fdisk::Fdisk::iter_mut)                                                                                                                                                                                                                                                           |
+//! | [`fdisk_next_label`][141]                     | [`Fdisk::iter`](crate::fdisk::Fdisk::iter) <br> [`Fdisk::iter_mut`](crate::fdisk::Fdisk::iter_mut) <br> [`Fdisk::supported_labels`](crate::fdisk::Fdisk::supported_labels)                                                                                                                                                                                   |
 //! | [`fdisk_get_npartitions`][142]                | [`Fdisk::partition_table_max_partitions`](crate::fdisk::Fdisk::partition_table_max_partitions)                                                                                                                                                                                                                                                               |
 //! | [`fdisk_is_label`][143]()                     |                                                                                                                                                                                                                                                                                                                                                              |
 //! | [`fdisk_label_advparse_parttype`][144]        | [`PartitionTable::partition_type_parse`](crate::core::partition_table::PartitionTable::partition_type_parse)                                                                                                                                                                                                                                                 |
@@ -448,9 +448,9 @@
 //! | [`fdisk_delete_all_partitions`][169]           | [`Fdisk::partition_delete_all`](crate::fdisk::Fdisk::partition_delete_all)                                                                                                                     |
 //! | [`fdisk_delete_partition`][170]                | [`Fdisk::partition_delete`](crate::fdisk::Fdisk::partition_delete)                                                                                                                             |
 //! | [`fdisk_get_partition`][171]                   | [`Fdisk::partition_by_number`](crate::fdisk::Fdisk::partition_by_number) <br> [`Fdisk::partition_by_number_mut`](crate::fdisk::Fdisk::partition_by_number_mut)                                 |
-//! | [`fdisk_is_partition_used`][172]               | [`Fdisk::partition_is_number_in_use`](crate::fdisk::Fdisk::partition_is_number_in_use)                                                                                                         |
+//! | [`fdisk_is_partition_used`][172]               | [`Fdisk::partition_is_number_in_use`](crate::fdisk::Fdisk::partition_is_number_in_use)<br>[`Fdisk::is_partition_slot_used`](crate::fdisk::Fdisk::is_partition_slot_used)                       |
 //! | [`fdisk_set_partition`][173]                   | [`Fdisk::partition_override_settings`](crate::fdisk::Fdisk::partition_override_settings)                                                                                                       |
-//! | [`fdisk_wipe_partition`][174]                  | [`Fdisk::partition_wipe_activate`](crate::fdisk::Fdisk::partition_wipe_activate)<br>[`Fdisk::partition_wipe_deactivate`](crate::fdisk::Fdisk::partition_wipe_deactivate)                       |
+//! | [`fdisk_wipe_partition`][174]                  | [`Fdisk::partition_wipe_activate`](crate::fdisk::Fdisk::partition_wipe_activate)<br>[`Fdisk::partition_wipe_deactivate`](crate::fdisk::Fdisk::partition_wipe_deactivate)<br>[`Fdisk::partition_add_wiping_signatures`](crate::fdisk::Fdisk::partition_add_wiping_signatures) |
 //! | [`fdisk_new_partition`][175]                   | [`Partition::builder`](crate::core::partition::Partition::builder)                                                                                                                             |
 //! | [`fdisk_partition_cmp_partno`][176]            | [`Partition::compare_partition_numbers`](crate::core::partition::Partition::compare_partition_numbers)                                                                                         |
 //! | [`fdisk_partition_cmp_start`][177]             | [`Partition::compare_starting_sectors`](crate::core::partition::Partition::compare_starting_sectors)                                                                                           |
@@ -476,7 +476,7 @@
 //! | [`fdisk_partition_is_nested`][197]             | [`Partition::is_nested`](crate::core::partition::Partition::is_nested)                                                                                                                         |
 //! | [`fdisk_partition_is_used`][198]               | [`Partition::points_to_used_area`](crate::core::partition::Partition::points_to_used_area)                                                                                                     |
 //! | [`fdisk_partition_is_wholedisk`][199]          | [`Partition::is_whole_disk`](crate::core::partition::Partition::is_whole_disk)                                                                                                                 |
-//! | [`fdisk_partition_next_partno`][200]           | [`Fdisk::partition_ask_next_number`](crate::fdisk::Fdisk::partition_ask_next_number)<br>[`Fdisk::partition_next_number`](crate::fdisk::Fdisk::partition_next_number)                           |
+//! | [`fdisk_partition_next_partno`][200]           | [`Fdisk::partition_ask_next_number`](crate::fdisk::Fdisk::partition_ask_next_number)<br>[`Fdisk::partition_next_number`](crate::fdisk::Fdisk::partition_next_number)<br>[`Fdisk::next_free_partition_number`](crate::fdisk::Fdisk::next_free_partition_number) |
 //! | [`fdisk_partition_partno_follow_default`][201] | Managed internally by [`PartitionBuilder`](crate::core::partition::PartitionBuilder).                                                                                                          |
 //! | [`fdisk_partition_set_attrs`][202]             | [`PartitionBuilder::attribute_bits`](crate::core::partition::PartitionBuilder::attribute_bits)                                                                                                 |
 //! | [`fdisk_partition_set_name`][203]              | [`PartitionBuilder::name`](crate::core::partition::PartitionBuilder::name)                                                                                                                     |
@@ -567,7 +567,7 @@
 //! | [`fdisk_table_is_empty`][230]                | [`PartitionList::is_empty`](crate::core::partition::PartitionList::is_empty)                                                                                                                                                       |
 //! | [`fdisk_table_next_partition`][231]          | [`PartitionList::iter`](crate::core::partition::PartitionList::iter) <br> [`PartitionList::iter_mut`](crate::core::partition::PartitionList::iter_mut)                                                                             |
 //! | [`fdisk_table_remove_partition`][232]        | [`PartitionList::remove`](crate::core::partition::PartitionList::remove)                                                                                                                                                           |
-//! | [`fdisk_table_sort_partitions`][233]         | Can not implement without a data pointer in the `cmp` function see [Passing Rust closure to C](http://blog.sagetheprogrammer.com/neat-rust-tricks-passing-rust-closures-to-c)                                                      |
+//! | [`fdisk_table_sort_partitions`][233]         | [`PartitionList::sort_by`](crate::core::partition::PartitionList::sort_by)                                                                                                                                                         |
 //! | [`fdisk_table_wrong_order`][234]             | [`PartitionList::is_not_in_increasing_order`](crate::core::partition::PartitionList::is_not_in_increasing_order)                                                                                                                   |
 //! | [`fdisk_unref_table`][235]                   | [`PartitionList`](crate::core::partition::PartitionList) is automatically deallocated when it goes out of scope.                                                                                                                   |
 //!
@@ -706,7 +706,7 @@
 //! | [`GPT_FLAG_GUIDSPECIFIC`][281]         | [`GPTFlag::GuidSpecific`](crate::core::partition::GPTFlag::GuidSpecific)                                                                                                                                                                                                                                           |
 //! | [`fdisk_gpt_is_hybrid`][282]           | [`FdiskGPTExt::gpt_is_hybrid`](crate::fdisk::FdiskGPTExt::gpt_is_hybrid)                                                                                                                                                                                                                                           |
 //! | [`fdisk_gpt_get_partition_attrs`][283] | [`FdiskGPTExt::gpt_attribute_bits`](crate::fdisk::FdiskGPTExt::gpt_attribute_bits)                                                                                                                                                                                                                                 |
-//! | [`fdisk_gpt_set_partition_attrs`][284] | [`FdiskGPTExt::gpt_set_attribute_bits`](crate::fdisk::FdiskGPTExt::gpt_set_attribute_bits)                                                                                                                                                                                                                         |
+//! | [`fdisk_gpt_set_partition_attrs`][284] | [`FdiskGPTExt::gpt_set_attribute_bits`](crate::fdisk::FdiskGPTExt::gpt_set_attribute_bits)<br>[`FdiskGPTExt::gpt_clear_attribute_bits`](crate::fdisk::FdiskGPTExt::gpt_clear_attribute_bits)                                                                                                                       |
 //! | [`fdisk_gpt_set_npartitions`][285]     | [`FdiskGPTExt::gpt_set_partition_entry_array_size`](crate::fdisk::FdiskGPTExt::gpt_set_partition_entry_array_size)                                                                                                                                                                                                 |
 //! | [`fdisk_gpt_disable_relocation`][286]  | [`PartitionTableGPTExt::gpt_enable_backup_header_relocation`](crate::core::partition_table::PartitionTableGPTExt::gpt_enable_backup_header_relocation)<br>[`PartitionTableGPTExt::gpt_disable_backup_header_relocation`](crate::core::partition_table::PartitionTableGPTExt::gpt_disable_backup_header_relocation) |
 //! | [`fdisk_gpt_enable_minimize`][287]     | [`PartitionTableGPTExt::gpt_enable_minimize_footprint`](crate::core::partition_table::PartitionTableGPTExt::gpt_enable_minimize_footprint)<br>[`PartitionTableGPTExt::gpt_disable_minimize_footprint`](crate::core::partition_table::PartitionTableGPTExt::gpt_disable_minimize_footprint)                         |
@@ -745,7 +745,7 @@
 //! | [`SGI_FLAG_BOOT`][293]          | [`SGIFlag::Boot`](crate::core::partition::SGIFlag::Boot)                         |
 //! | [`SGI_FLAG_SWAP`][294]          | [`SGIFlag::Swap`](crate::core::partition::SGIFlag::Swap)                         |
 //! | [`fdisk_sgi_create_info`][295]  | [`FdiskSGIExt::sgi_add_hint`](crate::fdisk::FdiskSGIExt::sgi_add_hint)           |
-//! | [`fdisk_sgi_set_bootfile`][296] | [`FdiskSGIExt::sgi_set_boot_file`](crate::fdisk::FdiskSGIExt::sgi_set_boot_file) |
+//! | [`fdisk_sgi_set_bootfile`][296] | [`FdiskSGIExt::sgi_set_boot_file`](crate::fdisk::FdiskSGIExt::sgi_set_boot_file)<br>[`FdiskSGIExt::sgi_set_boot_file_path`](crate::fdisk::FdiskSGIExt::sgi_set_boot_file_path) |
 //!
 //! [293]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libfdisk-docs/libfdisk-SGI.html#SGI-FLAG-BOOT:CAPS
 //! [294]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libfdisk-docs/libfdisk-SGI.html#SGI-FLAG-SWAP:CAPS